@@ -9,7 +9,7 @@ struct LimitMethodToggle {
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(PowerSystemPlugin)
+        .add_plugins(PowerSystemPlugin::<Power>::default())
         .add_systems(Startup, setup)
         .add_systems(
             Update,
@@ -65,7 +65,7 @@ fn setup(mut commands: Commands) {
     commands.insert_resource(LimitMethodToggle::default());
 
     // Camera
-    commands.spawn(Camera2d::default());
+    commands.spawn(Camera2d);
 
     // Spawn player with power system and movement
     commands.spawn((
@@ -77,7 +77,7 @@ fn setup(mut commands: Commands) {
         },
         Transform::from_translation(Vec3::ZERO),
         // Power system
-        PowerBundle::with_max_power(100.0),
+        PowerBundle::<Power>::with_max_power(100.0),
         // Player movement
         Player {
             velocity: Vec2::ZERO,
@@ -255,7 +255,9 @@ fn handle_input(
 
         if player.dash_cooldown.is_finished() {
             // Try to spend power for dash
-            if power_system.try_spend(settings.power_cost) {
+            if let Err(err) = power_system.try_spend(settings.power_cost) {
+                info!("Dash failed: {} (need {})", err, settings.power_cost);
+            } else {
                 // Dash successful - add dash velocity
                 let dash_direction = if movement != Vec2::ZERO {
                     movement
@@ -267,11 +269,6 @@ fn handle_input(
                 player.dash_cooldown.reset();
 
                 info!("Dash successful! Power spent: {}", settings.power_cost);
-            } else {
-                info!(
-                    "Dash failed: Not enough power (need {})",
-                    settings.power_cost
-                );
             }
         } else {
             info!("Dash failed: Still on cooldown");
@@ -286,16 +283,17 @@ fn handle_input(
 
     if keyboard.just_pressed(KeyCode::KeyL) {
         if toggle.use_try_methods {
-            if power_system.try_limit_points(
+            match power_system.try_limit_points(
                 1,
                 30.0,
                 Color::srgba(0.8, 0.0, 0.8, 0.7),
                 Some(5.0), // 5 second duration
                 false,
             ) {
-                info!("Applied power limit (30 points for 5 seconds) using try_method");
-            } else {
-                info!("Failed to apply power limit using try_method");
+                Ok(()) => {
+                    info!("Applied power limit (30 points for 5 seconds) using try_method")
+                }
+                Err(err) => info!("Failed to apply power limit using try_method: {}", err),
             }
         } else {
             power_system.limit_points(
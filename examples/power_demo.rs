@@ -9,7 +9,7 @@ struct LimitMethodToggle {
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(PowerSystemPlugin)
+        .add_plugins(PowerSystemPlugin::<Power>::default())
         .add_systems(Startup, setup)
         .add_systems(
             Update,
@@ -51,11 +51,11 @@ fn setup(mut commands: Commands) {
     commands.insert_resource(LimitMethodToggle::default());
 
     // Camera
-    commands.spawn(Camera2d::default());
+    commands.spawn(Camera2d);
 
     // Spawn player entity with power components
     commands
-        .spawn(PowerBundle::custom(100.0, 2.5, 5.0, 20.0))
+        .spawn(PowerBundle::<Power>::custom(100.0, 2.5, 5.0, 20.0))
         .insert(Player);
 
     // Create demo UI with buttons
@@ -515,13 +515,14 @@ fn create_demo_ui(commands: &mut Commands) {
 #[derive(Component)]
 struct StatusText;
 
+#[allow(clippy::type_complexity)]
 fn handle_button_clicks(
     mut interaction_query: Query<
         (&Interaction, &DemoButton, &mut BackgroundColor),
         (Changed<Interaction>, With<Button>),
     >,
     mut power_system: PowerSystem,
-    mut power_level_query: Query<&mut PowerLevel, With<Player>>,
+    power_level_query: Query<&PowerLevel, With<Player>>,
     mut status_text: Query<&mut Text, With<StatusText>>,
     toggle: Res<LimitMethodToggle>,
 ) {
@@ -535,14 +536,14 @@ fn handle_button_clicks(
                 if let Ok(mut text) = status_text.single_mut() {
                     **text = match button {
                         DemoButton::SpendSmall => {
-                            if power_system.try_spend(10.0) {
+                            if power_system.try_spend(10.0).is_ok() {
                                 "Status: Successfully spent 10 power".to_string()
                             } else {
                                 "Status: Failed to spend 10 power - insufficient power!".to_string()
                             }
                         }
                         DemoButton::SpendLarge => {
-                            if power_system.try_spend(30.0) {
+                            if power_system.try_spend(30.0).is_ok() {
                                 "Status: Successfully spent 30 power".to_string()
                             } else {
                                 "Status: Failed to spend 30 power - insufficient power!".to_string()
@@ -554,13 +555,16 @@ fn handle_button_clicks(
                         }
                         DemoButton::ApplyPointsLimit => {
                             if toggle.use_try_methods {
-                                if power_system.try_limit_points(
-                                    1,
-                                    20.0,
-                                    Color::srgba(0.8, 0.0, 0.8, 0.7),
-                                    None,
-                                    false,
-                                ) {
+                                if power_system
+                                    .try_limit_points(
+                                        1,
+                                        20.0,
+                                        Color::srgba(0.8, 0.0, 0.8, 0.7),
+                                        None,
+                                        false,
+                                    )
+                                    .is_ok()
+                                {
                                     "Status: Successfully applied 20 point limit (try_method)"
                                         .to_string()
                                 } else {
@@ -579,13 +583,16 @@ fn handle_button_clicks(
                         }
                         DemoButton::ApplyPercentLimit => {
                             if toggle.use_try_methods {
-                                if power_system.try_limit_percentage(
-                                    2,
-                                    25.0,
-                                    Color::srgba(0.8, 0.8, 0.0, 0.7),
-                                    None,
-                                    true, // This one resets cooldown
-                                ) {
+                                if power_system
+                                    .try_limit_percentage(
+                                        2,
+                                        25.0,
+                                        Color::srgba(0.8, 0.8, 0.0, 0.7),
+                                        None,
+                                        true, // This one resets cooldown
+                                    )
+                                    .is_ok()
+                                {
                                     "Status: Successfully applied 25% limit (try_method, resets cooldown)".to_string()
                                 } else {
                                     "Status: Failed to apply percentage limit (try_method)"
@@ -605,13 +612,16 @@ fn handle_button_clicks(
                         }
                         DemoButton::ApplyTimedLimit => {
                             if toggle.use_try_methods {
-                                if power_system.try_limit_points(
-                                    3,
-                                    15.0,
-                                    Color::srgba(0.0, 0.8, 0.8, 0.7),
-                                    Some(5.0), // 5 second duration
-                                    false,
-                                ) {
+                                if power_system
+                                    .try_limit_points(
+                                        3,
+                                        15.0,
+                                        Color::srgba(0.0, 0.8, 0.8, 0.7),
+                                        Some(5.0), // 5 second duration
+                                        false,
+                                    )
+                                    .is_ok()
+                                {
                                     "Status: Successfully applied timed limit (try_method, 5s) - will auto-expire!".to_string()
                                 } else {
                                     "Status: Failed to apply timed limit (try_method)".to_string()
@@ -638,8 +648,9 @@ fn handle_button_clicks(
                         }
                         DemoButton::LevelUp => {
                             // Manually trigger level up for demo
-                            if let Ok(mut level) = power_level_query.single_mut() {
-                                level.experience = level.experience_to_next;
+                            if let Ok(level) = power_level_query.single() {
+                                power_system
+                                    .gain_experience(level.experience_to_next - level.experience);
                                 format!("Status: Level up to {} triggered!", level.level + 1)
                             } else {
                                 "Status: Failed to level up".to_string()
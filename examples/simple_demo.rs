@@ -9,7 +9,7 @@ struct LimitMethodToggle {
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(PowerSystemPlugin)
+        .add_plugins(PowerSystemPlugin::<Power>::default())
         .add_systems(Startup, setup)
         .add_systems(Update, (handle_keyboard_input, display_power_info))
         .run();
@@ -26,15 +26,15 @@ fn setup(mut commands: Commands) {
     commands.insert_resource(LimitMethodToggle::default());
 
     // Camera
-    commands.spawn(Camera2d::default());
+    commands.spawn(Camera2d);
 
     // Spawn player entity with power components
     commands
         .spawn((
-            PowerBar::new(100.0),
-            PowerLevel::default(),
-            PowerRegeneration::default(),
-            PowerLimits::default(),
+            PowerBar::<Power>::new(100.0),
+            PowerLevel::<Power>::default(),
+            PowerRegeneration::<Power>::default(),
+            PowerLimits::<Power>::default(),
         ))
         .insert(Player);
 
@@ -85,7 +85,7 @@ fn handle_keyboard_input(
 ) {
     // Space - Try to spend 10 power
     if keyboard.just_pressed(KeyCode::Space) {
-        if power_system.try_spend(10.0) {
+        if power_system.try_spend(10.0).is_ok() {
             info!("Successfully spent 10 power");
         } else {
             info!("Failed to spend 10 power - insufficient power!");
@@ -94,7 +94,7 @@ fn handle_keyboard_input(
 
     // S - Try to spend 30 power
     if keyboard.just_pressed(KeyCode::KeyS) {
-        if power_system.try_spend(30.0) {
+        if power_system.try_spend(30.0).is_ok() {
             info!("Successfully spent 30 power");
         } else {
             info!("Failed to spend 30 power - insufficient power!");
@@ -110,7 +110,9 @@ fn handle_keyboard_input(
     // L - Apply fixed limit
     if keyboard.just_pressed(KeyCode::KeyL) {
         if toggle.use_try_methods {
-            if power_system.try_limit_points(1, 20.0, Color::srgba(0.8, 0.0, 0.8, 0.7), None, false)
+            if power_system
+                .try_limit_points(1, 20.0, Color::srgba(0.8, 0.0, 0.8, 0.7), None, false)
+                .is_ok()
             {
                 info!("Successfully applied 20 point limit (try_method)");
             } else {
@@ -125,13 +127,10 @@ fn handle_keyboard_input(
     // P - Apply percentage limit
     if keyboard.just_pressed(KeyCode::KeyP) {
         if toggle.use_try_methods {
-            if power_system.try_limit_percentage(
-                2,
-                25.0,
-                Color::srgba(0.8, 0.8, 0.0, 0.7),
-                None,
-                true,
-            ) {
+            if power_system
+                .try_limit_percentage(2, 25.0, Color::srgba(0.8, 0.8, 0.0, 0.7), None, true)
+                .is_ok()
+            {
                 info!("Successfully applied 25% limit (try_method, resets cooldown)");
             } else {
                 info!("Failed to apply percentage limit (try_method)");
@@ -147,13 +146,10 @@ fn handle_keyboard_input(
         // Check if Shift is held to apply timed limit, otherwise toggle method
         if keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight) {
             if toggle.use_try_methods {
-                if power_system.try_limit_points(
-                    3,
-                    15.0,
-                    Color::srgba(0.0, 0.8, 0.8, 0.7),
-                    Some(5.0),
-                    false,
-                ) {
+                if power_system
+                    .try_limit_points(3, 15.0, Color::srgba(0.0, 0.8, 0.8, 0.7), Some(5.0), false)
+                    .is_ok()
+                {
                     info!("Successfully applied timed limit (try_method, 5 seconds) - will auto-expire");
                 } else {
                     info!("Failed to apply timed limit (try_method)");
@@ -1,8 +1,22 @@
+use crate::{
+    error::FailureReason,
+    leveling::LevelingCurves,
+    limits::PowerLimits,
+    regen::{RegenCeiling, RegenCurve},
+    resource::{Power, PowerDirection, PowerResource},
+};
 use bevy::prelude::*;
+use std::marker::PhantomData;
 
 /// Main power bar component that tracks current and maximum power
-#[derive(Component, Debug, Clone)]
-pub struct PowerBar {
+///
+/// For an accumulating resource (see `PowerDirection`) `current` is the amount
+/// built up, so spending raises it and it overloads instead of knocking out.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct PowerBar<R: PowerResource = Power> {
     /// Current power value
     pub current: f32,
     /// Maximum power value (can be reduced by limits)
@@ -11,44 +25,125 @@ pub struct PowerBar {
     pub base_max: f32,
     /// Whether the player is knocked out
     pub is_knocked_out: bool,
+    /// Whether an accumulating resource hit max and is cooling down
+    pub is_overloaded: bool,
+    /// Power held back by uncommitted reservations
+    ///
+    /// Reservations only live until the spend is processed, so this is neither
+    /// saved nor reflected.
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub reserved: f32,
+    /// Resource this bar tracks
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub resource: PhantomData<R>,
 }
 
-impl Default for PowerBar {
+impl<R: PowerResource> Default for PowerBar<R> {
     fn default() -> Self {
-        Self {
-            current: 100.0,
-            max: 100.0,
-            base_max: 100.0,
-            is_knocked_out: false,
-        }
+        Self::new(100.0)
     }
 }
 
-impl PowerBar {
+impl<R: PowerResource> PowerBar<R> {
     /// Create a new power bar with specified max power
+    ///
+    /// Depleting resources start full, accumulating ones start empty.
     pub fn new(max_power: f32) -> Self {
+        let current = match R::DIRECTION {
+            PowerDirection::Depleting => max_power,
+            PowerDirection::Accumulating => 0.0,
+        };
         Self {
-            current: max_power,
+            current,
             max: max_power,
             base_max: max_power,
             is_knocked_out: false,
+            is_overloaded: false,
+            reserved: 0.0,
+            resource: PhantomData,
+        }
+    }
+
+    /// Power left before a knockout, or headroom left before an overload
+    pub fn remaining(&self) -> f32 {
+        match R::DIRECTION {
+            PowerDirection::Depleting => self.current,
+            PowerDirection::Accumulating => self.max - self.current,
+        }
+    }
+
+    /// Power that can still be spent, excluding reservations
+    pub fn available(&self) -> f32 {
+        self.remaining() - self.reserved
+    }
+
+    /// Check whether `amount` can be spent without knocking out or overloading
+    pub fn check_spend(&self, amount: f32) -> Result<(), FailureReason> {
+        if self.is_knocked_out {
+            Err(FailureReason::KnockedOut)
+        } else if self.is_overloaded {
+            Err(FailureReason::Overloaded)
+        } else if self.available() <= amount {
+            Err(FailureReason::InsufficientPower)
+        } else {
+            Ok(())
         }
     }
 
     /// Spend power, returns true if successful
     /// Prevents spending power that would result in knockout (power going to 0 or below)
+    /// or, for accumulating resources, in an overload
     pub fn spend(&mut self, amount: f32) -> bool {
-        if self.is_knocked_out || self.current <= amount {
+        if self.check_spend(amount).is_err() {
             return false;
         }
-        self.current -= amount;
+        match R::DIRECTION {
+            PowerDirection::Depleting => self.current -= amount,
+            PowerDirection::Accumulating => self.current += amount,
+        }
         true
     }
 
+    /// Hold back power for a later spend
+    ///
+    /// Reserved power is excluded from `check_spend` until it is released.
+    pub fn reserve(&mut self, amount: f32) -> Result<(), FailureReason> {
+        self.check_spend(amount)?;
+        self.reserved += amount;
+        Ok(())
+    }
+
+    /// Release previously reserved power
+    pub fn release(&mut self, amount: f32) {
+        self.reserved = (self.reserved - amount).max(0.0);
+    }
+
+    /// Drain power, clamped to zero
+    ///
+    /// Unlike `spend` this never refuses; reaching zero leads to a knockout.
+    /// Accumulating resources fill up to max instead and overload.
+    pub fn drain(&mut self, amount: f32) {
+        if self.is_knocked_out {
+            return;
+        }
+        match R::DIRECTION {
+            PowerDirection::Depleting => self.current = (self.current - amount).max(0.0),
+            PowerDirection::Accumulating => self.current = (self.current + amount).min(self.max),
+        }
+    }
+
     /// Add power, clamped to max
+    ///
+    /// Accumulating resources are lowered toward zero instead.
     pub fn add(&mut self, amount: f32) {
-        if !self.is_knocked_out {
-            self.current = (self.current + amount).min(self.max);
+        if self.is_knocked_out {
+            return;
+        }
+        match R::DIRECTION {
+            PowerDirection::Depleting => self.current = (self.current + amount).min(self.max),
+            PowerDirection::Accumulating => self.current = (self.current - amount).max(0.0),
         }
     }
 
@@ -60,6 +155,21 @@ impl PowerBar {
         }
     }
 
+    /// Recalculate max from base_max and the active limits, clamping current to it
+    ///
+    /// This is the single source of truth for `max`: it always ends up as
+    /// `base_max - total_reduction`, never below zero.
+    pub fn recalculate_max(&mut self, limits: Option<&mut PowerLimits<R>>) {
+        let total_reduction = limits
+            .map(|limits| {
+                limits.recalculate(self.base_max);
+                limits.total_reduction()
+            })
+            .unwrap_or(0.0);
+        self.max = (self.base_max - total_reduction).max(0.0);
+        self.current = self.current.min(self.max);
+    }
+
     /// Get power percentage (0.0 to 1.0)
     pub fn percentage(&self) -> f32 {
         if self.max > 0.0 {
@@ -70,58 +180,85 @@ impl PowerBar {
     }
 }
 
+/// Marks the entity used by the `PowerSystem` convenience methods
+///
+/// Only one entity may carry this marker, with several the convenience methods
+/// fail with `PowerError::AmbiguousPrimaryEntity`. When no entity has it, they
+/// fall back to the only `PowerBar` in the world.
+#[derive(Component, Reflect, Debug, Clone, Copy, Default)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct PrimaryPower;
+
 /// Tracks the power level for progression
-#[derive(Component, Debug, Clone)]
-pub struct PowerLevel {
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct PowerLevel<R: PowerResource = Power> {
     /// Current level
     pub level: u32,
     /// Experience or progression points
     pub experience: f32,
     /// Experience needed for next level
+    ///
+    /// Set from the `LevelingCurves` when the component is added.
     pub experience_to_next: f32,
+    /// Resource whose max power grows with the level
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub resource: PhantomData<R>,
 }
 
-impl Default for PowerLevel {
+impl<R: PowerResource> Default for PowerLevel<R> {
     fn default() -> Self {
         Self {
             level: 1,
             experience: 0.0,
             experience_to_next: 100.0,
+            resource: PhantomData,
         }
     }
 }
 
-impl PowerLevel {
-    /// Level up and calculate new max power bonus
+impl<R: PowerResource> PowerLevel<R> {
+    /// Level up with the default curves and calculate new max power bonus
+    ///
+    /// Experience beyond the requirement carries over to the next level.
     pub fn level_up(&mut self) -> f32 {
-        self.level += 1;
-        self.experience = 0.0;
-        self.experience_to_next *= 1.5; // Increase exp requirement
-
-        // Calculate power bonus - diminishing returns
-        // let bonus = (20.0 / (10.0 + (self.level as f32 - 1.0) * 0.2));
+        self.level_up_with(&LevelingCurves::default())
+    }
 
-        let bonus = 5.0
-            + (self.level as f32 - 44.0).clamp(0., 1.0) * 3.0
-            + (self.level as f32 - 64.0).clamp(0., 1.0) * 4.0
-            + (self.level as f32 - 84.0).clamp(0., 1.0) * -4.0
-            + (self.level as f32 - 104.0).clamp(0., 1.0) * -3.0;
-        bonus
+    /// Level up using the given curves and calculate new max power bonus
+    pub fn level_up_with(&mut self, curves: &LevelingCurves) -> f32 {
+        self.experience = (self.experience - self.experience_to_next).max(0.0);
+        self.level += 1;
+        self.experience_to_next = curves.experience_to_next.evaluate(self.level);
+        curves.power_bonus.evaluate(self.level)
     }
 
     /// Add experience and check for level up
     pub fn add_experience(&mut self, amount: f32) -> bool {
         self.experience += amount;
-        if self.experience >= self.experience_to_next {
-            return true;
-        }
-        false
+        self.can_level_up()
+    }
+
+    /// Check if enough experience has been gathered for the next level
+    ///
+    /// Also false when the requirement is too small to take off the gathered
+    /// experience at f32 precision, leveling up then would cost nothing.
+    pub fn can_level_up(&self) -> bool {
+        self.experience_to_next > 0.0
+            && self.experience >= self.experience_to_next
+            && self.experience - self.experience_to_next < self.experience
     }
 }
 
 /// Handles power regeneration mechanics
-#[derive(Component, Debug, Clone)]
-pub struct PowerRegeneration {
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct PowerRegeneration<R: PowerResource = Power> {
     /// Time since last power spend
     pub time_since_spend: f32,
     /// Delay before regeneration starts
@@ -136,9 +273,20 @@ pub struct PowerRegeneration {
     pub ramp_speed: f32,
     /// Whether regeneration is active
     pub is_active: bool,
+    /// How the rate develops once regeneration is active
+    pub curve: RegenCurve,
+    /// Optional cap below max that passive regeneration stops at
+    ///
+    /// On accumulating resources it caps the headroom decay restores instead,
+    /// except while overloaded, when the bar always cools down fully.
+    pub ceiling: Option<RegenCeiling>,
+    /// Resource this regenerates
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub resource: PhantomData<R>,
 }
 
-impl Default for PowerRegeneration {
+impl<R: PowerResource> Default for PowerRegeneration<R> {
     fn default() -> Self {
         Self {
             time_since_spend: 0.0,
@@ -148,11 +296,14 @@ impl Default for PowerRegeneration {
             max_rate: 20.0,
             ramp_speed: 2.0,
             is_active: false,
+            curve: RegenCurve::default(),
+            ceiling: None,
+            resource: PhantomData,
         }
     }
 }
 
-impl PowerRegeneration {
+impl<R: PowerResource> PowerRegeneration<R> {
     /// Reset regeneration when power is spent
     pub fn reset(&mut self) {
         self.time_since_spend = 0.0;
@@ -161,22 +312,38 @@ impl PowerRegeneration {
     }
 
     /// Update regeneration state
-    pub fn update(&mut self, delta: f32) {
+    ///
+    /// `max_power` is only used by `RegenCurve::PercentOfMax`.
+    pub fn update(&mut self, delta: f32, max_power: f32) {
         self.time_since_spend += delta;
 
         if self.time_since_spend >= self.regen_delay {
             self.is_active = true;
-            // Ramp up regeneration rate
-            if self.current_rate < self.max_rate {
-                self.current_rate =
-                    (self.current_rate + self.ramp_speed * delta).min(self.max_rate);
-                if self.current_rate == 0.0 {
-                    self.current_rate = self.base_rate;
-                }
+            self.current_rate = self.rate_at(self.time_since_spend - self.regen_delay, max_power);
+        }
+    }
+
+    /// Regeneration rate after regenerating for `active_time` seconds
+    pub fn rate_at(&self, active_time: f32, max_power: f32) -> f32 {
+        match &self.curve {
+            RegenCurve::Constant => self.base_rate,
+            RegenCurve::Linear => {
+                (self.base_rate + self.ramp_speed * active_time).min(self.max_rate)
+            }
+            RegenCurve::EaseIn { duration } => {
+                self.base_rate
+                    + (self.max_rate - self.base_rate) * RegenCurve::ease_in(*duration, active_time)
             }
+            RegenCurve::PercentOfMax(percent) => max_power * (percent / 100.0),
+            RegenCurve::Sampled { step, rates } => RegenCurve::sample(*step, rates, active_time),
         }
     }
 
+    /// Power regeneration refills to for the given max
+    pub fn ceiling_for(&self, max: f32) -> f32 {
+        self.ceiling.map_or(max, |ceiling| ceiling.value_for(max))
+    }
+
     /// Get the current regeneration amount for this frame
     pub fn get_regen_amount(&self, delta: f32) -> f32 {
         if self.is_active {
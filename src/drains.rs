@@ -0,0 +1,109 @@
+use crate::{
+    events::ChangeMode,
+    resource::{Power, PowerResource},
+};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Why a continuous drain ended
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DrainEndReason {
+    /// Stopped by a `StopDrainEvent`
+    Stopped,
+    /// Replaced by a new drain with the same ID
+    Replaced,
+    /// The entity ran out of power
+    Exhausted,
+    /// The entity was knocked out
+    KnockedOut,
+    /// The accumulating resource overloaded
+    Overloaded,
+}
+
+/// A continuous power drain, e.g. sprinting, a shield or a beam weapon
+#[derive(Reflect, Debug, Clone, PartialEq)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PowerDrain {
+    /// Unique identifier for this drain
+    pub id: u32,
+    /// Power drained per second
+    pub rate_per_sec: f32,
+    /// Whether regeneration stays paused while the drain runs
+    pub pauses_regen: bool,
+    /// `Safe` drains end before they would knock the entity out, `Drain`
+    /// drains run down to zero and knock it out
+    pub mode: ChangeMode,
+}
+
+impl PowerDrain {
+    /// Create a safe drain that pauses regeneration
+    pub fn new(id: u32, rate_per_sec: f32) -> Self {
+        Self {
+            id,
+            rate_per_sec,
+            pauses_regen: true,
+            mode: ChangeMode::Safe,
+        }
+    }
+
+    /// Set how the drain treats running out of power
+    pub fn with_mode(mut self, mode: ChangeMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set whether regeneration stays paused while the drain runs
+    pub fn with_pauses_regen(mut self, pauses_regen: bool) -> Self {
+        self.pauses_regen = pauses_regen;
+        self
+    }
+}
+
+/// Component holding every drain active on an entity
+#[derive(Component, Reflect, Debug, Clone, Default)]
+#[reflect(Component, Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PowerDrains<R: PowerResource = Power> {
+    pub drains: Vec<PowerDrain>,
+    /// Resource the drains take from
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> PowerDrains<R> {
+    /// Start a drain, returning the drain it replaced if one had the same ID
+    pub fn start(&mut self, drain: PowerDrain) -> Option<PowerDrain> {
+        match self.drains.iter_mut().find(|active| active.id == drain.id) {
+            Some(active) => Some(std::mem::replace(active, drain)),
+            None => {
+                self.drains.push(drain);
+                None
+            }
+        }
+    }
+
+    /// Stop a drain by ID
+    pub fn stop(&mut self, id: u32) -> Option<PowerDrain> {
+        let index = self.drains.iter().position(|drain| drain.id == id)?;
+        Some(self.drains.remove(index))
+    }
+
+    /// Check if a drain is active
+    pub fn is_active(&self, id: u32) -> bool {
+        self.drains.iter().any(|drain| drain.id == id)
+    }
+
+    /// Check if any active drain pauses regeneration
+    pub fn pauses_regen(&self) -> bool {
+        self.drains.iter().any(|drain| drain.pauses_regen)
+    }
+
+    /// Combined drain rate of every active drain
+    pub fn total_rate(&self) -> f32 {
+        self.drains.iter().map(|drain| drain.rate_per_sec).sum()
+    }
+}
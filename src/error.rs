@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use std::fmt;
+
+/// Why a spend or limit was rejected
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Debug, Clone, PartialEq, Hash)]
+pub enum FailureReason {
+    /// Not enough power, or headroom on an accumulating resource, to pay the cost
+    InsufficientPower,
+    /// The entity is knocked out
+    KnockedOut,
+    /// Applying it would knock the entity out
+    WouldKnockOut,
+    /// The entity is missing a required power component
+    MissingComponent,
+    /// The entity is in a grace window that blocks spends or defers limits
+    InGrace,
+    /// The accumulating resource is overloaded
+    Overloaded,
+    /// Applying it would overload the accumulating resource
+    WouldOverload,
+}
+
+/// Errors returned by the `PowerSystem` API
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerError {
+    /// The entity does not have a `PowerBar` component
+    MissingPowerBar(Entity),
+    /// Not enough power, or headroom on an accumulating resource, to pay the cost
+    InsufficientPower,
+    /// The entity is knocked out
+    KnockedOut,
+    /// Applying the change would knock the entity out
+    WouldKnockOut,
+    /// The entity is in a grace window that blocks spends or defers limits
+    InGrace,
+    /// The accumulating resource is overloaded
+    Overloaded,
+    /// Applying the change would overload the accumulating resource
+    WouldOverload,
+    /// No primary power entity could be resolved for a convenience method
+    NoPrimaryEntity,
+    /// More than one entity is marked with `PrimaryPower`
+    AmbiguousPrimaryEntity,
+    /// A `PowerSnapshot` was written by a newer version of the format
+    UnsupportedSnapshotVersion(u32),
+}
+
+impl fmt::Display for PowerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PowerError::MissingPowerBar(entity) => {
+                write!(f, "entity {entity} has no PowerBar component")
+            }
+            PowerError::InsufficientPower => write!(f, "not enough power"),
+            PowerError::KnockedOut => write!(f, "entity is knocked out"),
+            PowerError::WouldKnockOut => write!(f, "change would knock the entity out"),
+            PowerError::InGrace => write!(f, "entity is in a grace window"),
+            PowerError::Overloaded => write!(f, "resource is overloaded"),
+            PowerError::WouldOverload => write!(f, "change would overload the resource"),
+            PowerError::NoPrimaryEntity => write!(
+                f,
+                "no primary power entity: mark one with PrimaryPower or spawn exactly one PowerBar"
+            ),
+            PowerError::AmbiguousPrimaryEntity => {
+                write!(f, "more than one entity is marked with PrimaryPower")
+            }
+            PowerError::UnsupportedSnapshotVersion(version) => {
+                write!(f, "unsupported power snapshot version {version}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PowerError {}
+
+impl PowerError {
+    /// The failure reason reported in rejection events, if any
+    pub fn reason(&self) -> Option<FailureReason> {
+        match self {
+            PowerError::MissingPowerBar(_) => Some(FailureReason::MissingComponent),
+            PowerError::InsufficientPower => Some(FailureReason::InsufficientPower),
+            PowerError::KnockedOut => Some(FailureReason::KnockedOut),
+            PowerError::WouldKnockOut => Some(FailureReason::WouldKnockOut),
+            PowerError::InGrace => Some(FailureReason::InGrace),
+            PowerError::Overloaded => Some(FailureReason::Overloaded),
+            PowerError::WouldOverload => Some(FailureReason::WouldOverload),
+            PowerError::NoPrimaryEntity
+            | PowerError::AmbiguousPrimaryEntity
+            | PowerError::UnsupportedSnapshotVersion(_) => None,
+        }
+    }
+}
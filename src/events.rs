@@ -1,27 +1,127 @@
-use crate::limits::LimitType;
+use crate::{
+    drains::{DrainEndReason, PowerDrain},
+    error::FailureReason,
+    limits::{LimitStacking, LimitType},
+    regen::RegenModifier,
+    resource::{Power, PowerResource},
+    revive::ReviveCancelReason,
+};
 use bevy::prelude::*;
+use std::marker::PhantomData;
 
 /// Event to spend power
-#[derive(Message, Debug, Clone)]
-pub struct SpendPowerEvent {
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct SpendPowerEvent<R: PowerResource = Power> {
     /// Entity with the PowerBar component
     pub entity: Entity,
     /// Amount of power to spend
     pub amount: f32,
+    /// Whether the amount was reserved on the `PowerBar` beforehand
+    pub reserved: bool,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> SpendPowerEvent<R> {
+    /// Create a spend event
+    pub fn new(entity: Entity, amount: f32) -> Self {
+        Self {
+            entity,
+            amount,
+            reserved: false,
+            resource: PhantomData,
+        }
+    }
+
+    /// Create a spend event that commits a reservation of `amount`
+    pub fn reserved(entity: Entity, amount: f32) -> Self {
+        Self {
+            entity,
+            amount,
+            reserved: true,
+            resource: PhantomData,
+        }
+    }
+}
+
+/// How a negative `PowerChangeEvent` is applied
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChangeMode {
+    /// Decrease like a spend: refused if it would knock the entity out
+    #[default]
+    Safe,
+    /// Decrease clamped to zero, knocking the entity out if it reaches zero
+    Drain,
 }
 
 /// Event to change power (add or subtract)
-#[derive(Message, Debug, Clone)]
-pub struct PowerChangeEvent {
+///
+/// The sign follows the resource, not the bar: on accumulating resources a
+/// negative amount raises the bar toward overload and a positive one lowers it.
+/// Use `build_up` and `vent` to say which way an accumulating bar should go.
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct PowerChangeEvent<R: PowerResource = Power> {
     /// Entity with the PowerBar component
     pub entity: Entity,
     /// Amount to change (negative for decrease)
+    ///
+    /// On accumulating resources a decrease raises the bar toward overload
+    /// and an increase lowers it.
     pub amount: f32,
+    /// How a decrease is applied
+    pub mode: ChangeMode,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> PowerChangeEvent<R> {
+    /// Create a change that never knocks the entity out
+    pub fn safe(entity: Entity, amount: f32) -> Self {
+        Self {
+            entity,
+            amount,
+            mode: ChangeMode::Safe,
+            resource: PhantomData,
+        }
+    }
+
+    /// Create a decrease by `amount` that drains down to zero and can knock the entity out
+    ///
+    /// Takes the magnitude like `EntityPower::drain`; the sign is ignored.
+    pub fn drain(entity: Entity, amount: f32) -> Self {
+        Self {
+            entity,
+            amount: -amount.abs(),
+            mode: ChangeMode::Drain,
+            resource: PhantomData,
+        }
+    }
+
+    /// Raise an accumulating bar by `amount`, overloading it if it fills up
+    ///
+    /// On depleting resources this drains the bar like `drain`.
+    pub fn build_up(entity: Entity, amount: f32) -> Self {
+        Self::drain(entity, amount)
+    }
+
+    /// Lower an accumulating bar by `amount`, e.g. venting heat
+    ///
+    /// On depleting resources this adds power like `safe(entity, amount)`.
+    pub fn vent(entity: Entity, amount: f32) -> Self {
+        Self::safe(entity, amount.abs())
+    }
 }
 
 /// Event to apply a power limit
-#[derive(Message, Debug, Clone)]
-pub struct ApplyLimitEvent {
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct ApplyLimitEvent<R: PowerResource = Power> {
     /// Entity to apply the limit to
     pub entity: Entity,
     /// Unique ID for this limit
@@ -34,9 +134,14 @@ pub struct ApplyLimitEvent {
     pub duration: Option<f32>,
     /// Whether this limit resets the regeneration cooldown
     pub resets_cooldown: bool,
+    /// How this limit stacks with active limits of the same ID
+    pub stacking: LimitStacking,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
 }
 
-impl ApplyLimitEvent {
+impl<R: PowerResource> ApplyLimitEvent<R> {
     /// Create a new limit event with points
     pub fn points(
         entity: Entity,
@@ -53,6 +158,8 @@ impl ApplyLimitEvent {
             color,
             duration,
             resets_cooldown,
+            stacking: LimitStacking::default(),
+            resource: PhantomData,
         }
     }
 
@@ -72,42 +179,535 @@ impl ApplyLimitEvent {
             color,
             duration,
             resets_cooldown,
+            stacking: LimitStacking::default(),
+            resource: PhantomData,
         }
     }
+
+    /// Set the stacking policy for this limit
+    pub fn with_stacking(mut self, stacking: LimitStacking) -> Self {
+        self.stacking = stacking;
+        self
+    }
 }
 
 /// Event to lift/remove a power limit
-#[derive(Message, Debug, Clone)]
-pub struct LiftLimitEvent {
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct LiftLimitEvent<R: PowerResource = Power> {
     /// Entity to remove the limit from
     pub entity: Entity,
     /// ID of the limit to remove
     pub id: u32,
+    /// Remove every stack of the limit instead of only the oldest one
+    pub all_stacks: bool,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> LiftLimitEvent<R> {
+    /// Lift every stack of a limit
+    pub fn all(entity: Entity, id: u32) -> Self {
+        Self {
+            entity,
+            id,
+            all_stacks: true,
+            resource: PhantomData,
+        }
+    }
+
+    /// Lift only the oldest stack of a limit
+    pub fn one(entity: Entity, id: u32) -> Self {
+        Self {
+            entity,
+            id,
+            all_stacks: false,
+            resource: PhantomData,
+        }
+    }
 }
 
 /// Event sent when player is knocked out
-#[derive(Message, Debug, Clone)]
-pub struct KnockedOutEvent {
+///
+/// With `KnockoutPolicy::Downed` this is sent once the entity bleeds out.
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct KnockedOutEvent<R: PowerResource = Power> {
     /// Entity that was knocked out
     pub entity: Entity,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event sent when an entity with `KnockoutPolicy::Downed` goes down
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct DownedEvent<R: PowerResource = Power> {
+    /// Entity that went down
+    pub entity: Entity,
+    /// Seconds until it bleeds out without a revive
+    pub bleed_out: f32,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event sent when an accumulating resource fills up and overloads
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct OverloadedEvent<R: PowerResource = Power> {
+    /// Entity whose resource overloaded
+    pub entity: Entity,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event sent when an overloaded resource has decayed back to zero
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct OverloadClearedEvent<R: PowerResource = Power> {
+    /// Entity whose resource cooled down
+    pub entity: Entity,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
 }
 
 /// Event to revive a knocked out player
-#[derive(Message, Debug, Clone)]
-pub struct ReviveEvent {
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct ReviveEvent<R: PowerResource = Power> {
     /// Entity to revive
     pub entity: Entity,
     /// Amount of power to restore upon revival
     pub power_amount: f32,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> ReviveEvent<R> {
+    /// Create a revive event
+    pub fn new(entity: Entity, power_amount: f32) -> Self {
+        Self {
+            entity,
+            power_amount,
+            resource: PhantomData,
+        }
+    }
+}
+
+/// Event to grant experience to an entity with a PowerLevel
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct GainExperienceEvent<R: PowerResource = Power> {
+    /// Entity gaining experience
+    pub entity: Entity,
+    /// Amount of experience to add
+    pub amount: f32,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> GainExperienceEvent<R> {
+    /// Create an experience grant
+    pub fn new(entity: Entity, amount: f32) -> Self {
+        Self {
+            entity,
+            amount,
+            resource: PhantomData,
+        }
+    }
 }
 
 /// Event sent when player levels up
-#[derive(Message, Debug, Clone)]
-pub struct LevelUpEvent {
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct LevelUpEvent<R: PowerResource = Power> {
     /// Entity that leveled up
     pub entity: Entity,
     /// New level
     pub new_level: u32,
     /// Power bonus gained
     pub power_bonus: f32,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event sent when a limit is added to an entity, as a new limit, replacement or stack
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct LimitAppliedEvent<R: PowerResource = Power> {
+    /// Entity the limit was applied to
+    pub entity: Entity,
+    /// ID of the limit
+    pub id: u32,
+    /// Type of the limit
+    pub limit_type: LimitType,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event sent when an active limit's duration is refreshed or extended
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct LimitRefreshedEvent<R: PowerResource = Power> {
+    /// Entity with the limit
+    pub entity: Entity,
+    /// ID of the limit
+    pub id: u32,
+    /// Type of the limit
+    pub limit_type: LimitType,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event sent when a timed limit runs out
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct LimitExpiredEvent<R: PowerResource = Power> {
+    /// Entity the limit expired on
+    pub entity: Entity,
+    /// ID of the limit
+    pub id: u32,
+    /// Type of the limit
+    pub limit_type: LimitType,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event sent for every limit stack removed by a `LiftLimitEvent`
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct LimitLiftedEvent<R: PowerResource = Power> {
+    /// Entity the limit was lifted from
+    pub entity: Entity,
+    /// ID of the limit
+    pub id: u32,
+    /// Type of the limit
+    pub limit_type: LimitType,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// What caused a change of an entity's power
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Debug, Clone, PartialEq, Hash)]
+pub enum PowerChangeCause {
+    /// A `SpendPowerEvent` was applied
+    Spend,
+    /// Passive regeneration
+    Regen,
+    /// A `PowerChangeEvent` was applied
+    Change,
+    /// Max power changed because a limit was applied, lifted or expired
+    LimitClamp,
+    /// A `ReviveEvent` was applied
+    Revive,
+    /// Max power grew from a level up
+    LevelUp,
+    /// Max power was recalculated after a direct edit of `base_max` or `PowerLimits`
+    Recalculate,
+    /// Current power was zeroed by a knockout
+    Knockout,
+    /// An accumulating resource was filled to max by an overload
+    Overload,
+    /// A continuous `PowerDrain` ticked
+    Drain,
+}
+
+/// Event sent whenever an entity's current or max power changes
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct PowerChangedEvent<R: PowerResource = Power> {
+    /// Entity whose power changed
+    pub entity: Entity,
+    /// Current power before the change
+    pub old: f32,
+    /// Current power after the change
+    pub new: f32,
+    /// Max power after the change
+    pub max: f32,
+    /// What caused the change
+    pub cause: PowerChangeCause,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event sent when a spend is rejected
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct SpendFailedEvent<R: PowerResource = Power> {
+    /// Entity that tried to spend power
+    pub entity: Entity,
+    /// Amount of power that could not be spent
+    pub amount: f32,
+    /// Why the spend was rejected
+    pub reason: FailureReason,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event sent when a limit is rejected
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct LimitRejectedEvent<R: PowerResource = Power> {
+    /// Entity the limit was meant for
+    pub entity: Entity,
+    /// ID of the limit
+    pub id: u32,
+    /// Type of the limit
+    pub limit_type: LimitType,
+    /// Why the limit was rejected
+    pub reason: FailureReason,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event to start a continuous drain, replacing any drain with the same ID
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct StartDrainEvent<R: PowerResource = Power> {
+    /// Entity with the PowerBar component
+    pub entity: Entity,
+    /// The drain to start
+    pub drain: PowerDrain,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> StartDrainEvent<R> {
+    /// Create an event starting `drain`
+    pub fn new(entity: Entity, drain: PowerDrain) -> Self {
+        Self {
+            entity,
+            drain,
+            resource: PhantomData,
+        }
+    }
+}
+
+/// Event to stop a continuous drain
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct StopDrainEvent<R: PowerResource = Power> {
+    /// Entity with the PowerBar component
+    pub entity: Entity,
+    /// ID of the drain to stop
+    pub id: u32,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> StopDrainEvent<R> {
+    /// Create an event stopping the drain with ID `id`
+    pub fn new(entity: Entity, id: u32) -> Self {
+        Self {
+            entity,
+            id,
+            resource: PhantomData,
+        }
+    }
+}
+
+/// Event sent when a continuous drain ends
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct DrainEndedEvent<R: PowerResource = Power> {
+    /// Entity the drain ran on
+    pub entity: Entity,
+    /// ID of the drain
+    pub id: u32,
+    /// Why the drain ended
+    pub reason: DrainEndReason,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event to add a regeneration modifier, replacing any modifier with the same ID
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct ApplyRegenModifierEvent<R: PowerResource = Power> {
+    /// Entity with the PowerRegeneration component
+    pub entity: Entity,
+    /// The modifier to add
+    pub modifier: RegenModifier,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> ApplyRegenModifierEvent<R> {
+    /// Create an event adding `modifier`
+    pub fn new(entity: Entity, modifier: RegenModifier) -> Self {
+        Self {
+            entity,
+            modifier,
+            resource: PhantomData,
+        }
+    }
+}
+
+/// Event to remove a regeneration modifier
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct RemoveRegenModifierEvent<R: PowerResource = Power> {
+    /// Entity to remove the modifier from
+    pub entity: Entity,
+    /// ID of the modifier to remove
+    pub id: u32,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> RemoveRegenModifierEvent<R> {
+    /// Create an event removing the modifier with ID `id`
+    pub fn new(entity: Entity, id: u32) -> Self {
+        Self {
+            entity,
+            id,
+            resource: PhantomData,
+        }
+    }
+}
+
+/// Event sent when a timed regeneration modifier runs out
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct RegenModifierExpiredEvent<R: PowerResource = Power> {
+    /// Entity the modifier expired on
+    pub entity: Entity,
+    /// ID of the modifier
+    pub id: u32,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event to start a channelled revive of a knocked out entity
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct StartReviveEvent<R: PowerResource = Power> {
+    /// Entity performing the revive
+    pub reviver: Entity,
+    /// Knocked out entity to revive
+    pub target: Entity,
+    /// Seconds needed to complete the revive
+    pub duration: f32,
+    /// Power the target is revived with
+    pub power_amount: f32,
+    /// Power the reviver pays on completion (0 for free)
+    pub cost: f32,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> StartReviveEvent<R> {
+    /// Create an event starting a revive attempt
+    pub fn new(
+        reviver: Entity,
+        target: Entity,
+        duration: f32,
+        power_amount: f32,
+        cost: f32,
+    ) -> Self {
+        Self {
+            reviver,
+            target,
+            duration,
+            power_amount,
+            cost,
+            resource: PhantomData,
+        }
+    }
+}
+
+/// Event to cancel the revive attempt on a target
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct CancelReviveEvent<R: PowerResource = Power> {
+    /// Entity being revived
+    pub target: Entity,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> CancelReviveEvent<R> {
+    /// Create an event cancelling the revive attempt on `target`
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            resource: PhantomData,
+        }
+    }
+}
+
+/// Event sent when a revive attempt starts
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct ReviveStartedEvent<R: PowerResource = Power> {
+    /// Entity performing the revive
+    pub reviver: Entity,
+    /// Entity being revived
+    pub target: Entity,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event sent when a revive attempt is rejected or ends without reviving
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct ReviveCancelledEvent<R: PowerResource = Power> {
+    /// Entity that was performing the revive
+    pub reviver: Entity,
+    /// Entity that was being revived
+    pub target: Entity,
+    /// Why the attempt ended
+    pub reason: ReviveCancelReason,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event sent when a grace window starts
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct GraceStartedEvent<R: PowerResource = Power> {
+    /// Entity the window started on
+    pub entity: Entity,
+    /// Length of the window in seconds
+    pub duration: f32,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Event sent when a grace window ends
+#[derive(Message, Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+pub struct GraceEndedEvent<R: PowerResource = Power> {
+    /// Entity the window ended on
+    pub entity: Entity,
+    /// Resource the event applies to
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
 }
@@ -0,0 +1,169 @@
+#[cfg(feature = "serde")]
+use crate::limits::PowerLimit;
+use crate::{
+    events::ApplyLimitEvent,
+    resource::{Power, PowerResource},
+};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Per-entity settings for the grace window granted after a revive
+///
+/// Entities without this component get no grace window.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct GracePolicy {
+    /// Length of the window in seconds
+    pub duration: f32,
+    /// Scale applied to negative power changes during the window (0 blocks them)
+    pub damage_scale: f32,
+    /// Whether spends are rejected during the window
+    pub blocks_spends: bool,
+    /// Also start a window when limits take all max power, so further limits
+    /// are deferred while the entity is down
+    pub after_limit_knockout: bool,
+}
+
+impl Default for GracePolicy {
+    fn default() -> Self {
+        Self {
+            duration: 2.0,
+            damage_scale: 0.0,
+            blocks_spends: false,
+            after_limit_knockout: false,
+        }
+    }
+}
+
+/// Active grace window, present on an entity until it runs out
+///
+/// Limits applied during the window are held in `deferred_limits` and applied
+/// when it ends.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "GraceWindowData", into = "GraceWindowData", bound = "")
+)]
+pub struct GraceWindow<R: PowerResource = Power> {
+    /// Time left in the window
+    pub timer: Timer,
+    /// Negative change scale copied from the policy
+    pub damage_scale: f32,
+    /// Whether spends are rejected, copied from the policy
+    pub blocks_spends: bool,
+    /// Limits waiting for the window to end
+    pub deferred_limits: Vec<ApplyLimitEvent<R>>,
+    /// Resource the window protects
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Serialized form of a `GraceWindow`, storing the timer as plain seconds
+///
+/// Deferred limits are kept as `PowerLimit`s and loaded back without a target,
+/// `PowerSnapshot::apply` points them at the entity it restores.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct GraceWindowData {
+    /// Total duration in seconds
+    duration: f32,
+    /// Seconds already elapsed on the timer
+    #[serde(default)]
+    elapsed: f32,
+    damage_scale: f32,
+    blocks_spends: bool,
+    #[serde(default)]
+    deferred_limits: Vec<PowerLimit>,
+}
+
+#[cfg(feature = "serde")]
+impl<R: PowerResource> From<GraceWindow<R>> for GraceWindowData {
+    fn from(grace: GraceWindow<R>) -> Self {
+        Self {
+            duration: grace.timer.duration().as_secs_f32(),
+            elapsed: grace.timer.elapsed_secs(),
+            damage_scale: grace.damage_scale,
+            blocks_spends: grace.blocks_spends,
+            deferred_limits: grace
+                .deferred_limits
+                .into_iter()
+                .map(|event| {
+                    PowerLimit::new(
+                        event.id,
+                        event.limit_type,
+                        event.color,
+                        event.duration,
+                        event.resets_cooldown,
+                    )
+                    .with_stacking(event.stacking)
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<R: PowerResource> From<GraceWindowData> for GraceWindow<R> {
+    fn from(data: GraceWindowData) -> Self {
+        let mut timer = Timer::from_seconds(data.duration, TimerMode::Once);
+        timer.set_elapsed(std::time::Duration::from_secs_f32(data.elapsed));
+        Self {
+            timer,
+            damage_scale: data.damage_scale,
+            blocks_spends: data.blocks_spends,
+            deferred_limits: data
+                .deferred_limits
+                .into_iter()
+                .map(|limit| ApplyLimitEvent {
+                    entity: Entity::PLACEHOLDER,
+                    id: limit.id,
+                    limit_type: limit.limit_type,
+                    color: limit.color,
+                    duration: limit.duration.as_ref().map(|t| t.duration().as_secs_f32()),
+                    resets_cooldown: limit.resets_cooldown,
+                    stacking: limit.stacking,
+                    resource: PhantomData,
+                })
+                .collect(),
+            resource: PhantomData,
+        }
+    }
+}
+
+impl<R: PowerResource> GraceWindow<R> {
+    /// Start a window with the given policy
+    pub fn new(policy: &GracePolicy) -> Self {
+        Self {
+            timer: Timer::from_seconds(policy.duration, TimerMode::Once),
+            damage_scale: policy.damage_scale,
+            blocks_spends: policy.blocks_spends,
+            deferred_limits: Vec::new(),
+            resource: PhantomData,
+        }
+    }
+
+    /// Restart the window, keeping deferred limits
+    pub fn restart(&mut self, policy: &GracePolicy) {
+        self.timer = Timer::from_seconds(policy.duration, TimerMode::Once);
+        self.damage_scale = policy.damage_scale;
+        self.blocks_spends = policy.blocks_spends;
+    }
+
+    /// Scale a negative power change, positive changes pass through
+    pub fn scale_change(&self, amount: f32) -> f32 {
+        if amount < 0.0 {
+            amount * self.damage_scale.max(0.0)
+        } else {
+            amount
+        }
+    }
+
+    /// Seconds left in the window
+    pub fn remaining_secs(&self) -> f32 {
+        self.timer.remaining_secs()
+    }
+}
@@ -0,0 +1,136 @@
+use crate::resource::{Power, PowerResource};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// What happens after an entity's power runs out
+///
+/// Entities without this component use the plugin's `default_knockout_policy`,
+/// `KnockoutPolicy::Manual` unless configured otherwise.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Component, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KnockoutPolicy {
+    /// Stay knocked out until a `ReviveEvent`
+    #[default]
+    Manual,
+    /// Revive with `power` after `delay` seconds
+    ///
+    /// While limits hold max power at zero the revive waits until they lift.
+    AutoRevive { delay: f32, power: f32 },
+    /// Revive with `power` as soon as a limit is lifted or expires and max power is above zero
+    ReviveOnLimitLift { power: f32 },
+    /// Go down first and only get knocked out after `bleed_out` seconds without a revive
+    Downed { bleed_out: f32 },
+}
+
+/// Stage of a knocked out entity
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KnockoutStage {
+    /// Down but not out, waiting for a revive before bleeding out
+    Downed,
+    /// Fully knocked out
+    KnockedOut,
+}
+
+/// Knockout progress of an entity, present while it is knocked out
+///
+/// Inserted when power runs out and removed when the entity is revived.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "KnockoutStateData", into = "KnockoutStateData", bound = "")
+)]
+pub struct KnockoutState<R: PowerResource = Power> {
+    /// Current stage
+    pub stage: KnockoutStage,
+    /// Time until bleeding out or auto-reviving (None = wait for a revive)
+    pub timer: Option<Timer>,
+    /// Resource that ran out
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Serialized form of a `KnockoutState`, storing the timer as plain seconds
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct KnockoutStateData {
+    stage: KnockoutStage,
+    /// Total duration of the stage in seconds (None = wait for a revive)
+    duration: Option<f32>,
+    /// Seconds already elapsed on the timer
+    #[serde(default)]
+    elapsed: f32,
+}
+
+#[cfg(feature = "serde")]
+impl<R: PowerResource> From<KnockoutState<R>> for KnockoutStateData {
+    fn from(state: KnockoutState<R>) -> Self {
+        Self {
+            stage: state.stage,
+            duration: state.timer.as_ref().map(|t| t.duration().as_secs_f32()),
+            elapsed: state
+                .timer
+                .as_ref()
+                .map(|t| t.elapsed_secs())
+                .unwrap_or(0.0),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<R: PowerResource> From<KnockoutStateData> for KnockoutState<R> {
+    fn from(data: KnockoutStateData) -> Self {
+        let timer = data.duration.map(|secs| {
+            let mut timer = Timer::from_seconds(secs, TimerMode::Once);
+            timer.set_elapsed(std::time::Duration::from_secs_f32(data.elapsed));
+            timer
+        });
+        Self {
+            stage: data.stage,
+            timer,
+            resource: PhantomData,
+        }
+    }
+}
+
+impl<R: PowerResource> KnockoutState<R> {
+    /// Enter the first knockout stage for the given policy
+    pub fn for_policy(policy: KnockoutPolicy) -> Self {
+        let (stage, duration) = match policy {
+            KnockoutPolicy::Downed { bleed_out } => (KnockoutStage::Downed, Some(bleed_out)),
+            KnockoutPolicy::AutoRevive { delay, .. } => (KnockoutStage::KnockedOut, Some(delay)),
+            KnockoutPolicy::Manual | KnockoutPolicy::ReviveOnLimitLift { .. } => {
+                (KnockoutStage::KnockedOut, None)
+            }
+        };
+        Self {
+            stage,
+            timer: duration.map(|secs| Timer::from_seconds(secs, TimerMode::Once)),
+            resource: PhantomData,
+        }
+    }
+
+    /// Update the timer, returns true when it just finished
+    pub fn update(&mut self, delta: f32) -> bool {
+        if let Some(ref mut timer) = self.timer {
+            timer.tick(std::time::Duration::from_secs_f32(delta));
+            timer.just_finished()
+        } else {
+            false
+        }
+    }
+
+    /// Seconds left on the current stage (None if waiting for a revive)
+    pub fn remaining_secs(&self) -> Option<f32> {
+        self.timer.as_ref().map(|timer| timer.remaining_secs())
+    }
+
+    /// Check if the entity is downed rather than fully knocked out
+    pub fn is_downed(&self) -> bool {
+        self.stage == KnockoutStage::Downed
+    }
+}
@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use std::fmt;
+use std::sync::Arc;
+
+/// A curve mapping a level to a value, used for leveling progression
+///
+/// Custom curves hold a closure that can't be saved, see `CustomCurve`.
+#[derive(Reflect, Clone)]
+#[reflect(Debug, Clone)]
+pub enum LevelingCurve {
+    /// `base + per_level * (level - 1)`
+    Linear { base: f32, per_level: f32 },
+    /// `base * factor^(level - 1)`
+    Exponential { base: f32, factor: f32 },
+    /// Value per level starting at level 1, the last entry repeats for higher levels
+    Table(Vec<f32>),
+    /// Custom function of the level
+    Custom(#[reflect(ignore)] CustomCurve),
+}
+
+/// Closure of a custom `LevelingCurve`
+///
+/// Skipped by reflection, so scenes save custom curves without their function
+/// and load them back as the default: a curve that is always zero.
+#[derive(Clone)]
+pub struct CustomCurve(pub Arc<dyn Fn(u32) -> f32 + Send + Sync>);
+
+impl Default for CustomCurve {
+    fn default() -> Self {
+        Self(Arc::new(|_| 0.0))
+    }
+}
+
+impl LevelingCurve {
+    /// Create a curve from a custom function
+    pub fn custom(f: impl Fn(u32) -> f32 + Send + Sync + 'static) -> Self {
+        Self::Custom(CustomCurve(Arc::new(f)))
+    }
+
+    /// Evaluate the curve at the given level
+    pub fn evaluate(&self, level: u32) -> f32 {
+        let steps = level.saturating_sub(1);
+        match self {
+            LevelingCurve::Linear { base, per_level } => base + per_level * steps as f32,
+            LevelingCurve::Exponential { base, factor } => base * factor.powi(steps as i32),
+            LevelingCurve::Table(values) => values
+                .get(steps as usize)
+                .or(values.last())
+                .copied()
+                .unwrap_or(0.0),
+            LevelingCurve::Custom(CustomCurve(f)) => f(level),
+        }
+    }
+
+    /// Default max power bonus - flat gains with a bump between levels 45 and 104
+    ///
+    /// Built as a table so it survives saving in scenes.
+    pub fn default_power_bonus() -> Self {
+        Self::Table(
+            (1..=105)
+                .map(|level| match level {
+                    45..=64 | 85..=104 => 8.0,
+                    65..=84 => 12.0,
+                    _ => 5.0,
+                })
+                .collect(),
+        )
+    }
+}
+
+impl fmt::Debug for LevelingCurve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LevelingCurve::Linear { base, per_level } => f
+                .debug_struct("Linear")
+                .field("base", base)
+                .field("per_level", per_level)
+                .finish(),
+            LevelingCurve::Exponential { base, factor } => f
+                .debug_struct("Exponential")
+                .field("base", base)
+                .field("factor", factor)
+                .finish(),
+            LevelingCurve::Table(values) => f.debug_tuple("Table").field(values).finish(),
+            LevelingCurve::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Leveling progression settings
+///
+/// Inserted as a resource by the plugin for the global defaults. Add it as a
+/// component to override the progression of a single entity.
+#[derive(Resource, Component, Reflect, Debug, Clone)]
+#[reflect(Resource, Component, Default, Debug, Clone)]
+pub struct LevelingCurves {
+    /// Experience needed to advance from a level to the next
+    pub experience_to_next: LevelingCurve,
+    /// Max power bonus gained when reaching a level
+    pub power_bonus: LevelingCurve,
+}
+
+impl Default for LevelingCurves {
+    fn default() -> Self {
+        Self {
+            experience_to_next: LevelingCurve::Exponential {
+                base: 100.0,
+                factor: 1.5,
+            },
+            power_bonus: LevelingCurve::default_power_bonus(),
+        }
+    }
+}
@@ -1,26 +1,78 @@
 mod components;
+mod drains;
+mod error;
 mod events;
+mod grace;
+mod knockout;
+mod leveling;
 mod limits;
 mod plugin;
+mod regen;
+mod resource;
+mod revive;
+mod settings;
+mod snapshot;
 mod systems;
+#[cfg(feature = "ui")]
 mod ui;
 
-pub use components::{PowerBar, PowerLevel, PowerRegeneration};
+pub use components::{PowerBar, PowerLevel, PowerRegeneration, PrimaryPower};
+pub use drains::{DrainEndReason, PowerDrain, PowerDrains};
+pub use error::{FailureReason, PowerError};
 pub use events::{
-    ApplyLimitEvent, KnockedOutEvent, LevelUpEvent, LiftLimitEvent, PowerChangeEvent, ReviveEvent,
-    SpendPowerEvent,
+    ApplyLimitEvent, ApplyRegenModifierEvent, CancelReviveEvent, ChangeMode, DownedEvent,
+    DrainEndedEvent, GainExperienceEvent, GraceEndedEvent, GraceStartedEvent, KnockedOutEvent,
+    LevelUpEvent, LiftLimitEvent, LimitAppliedEvent, LimitExpiredEvent, LimitLiftedEvent,
+    LimitRefreshedEvent, LimitRejectedEvent, OverloadClearedEvent, OverloadedEvent,
+    PowerChangeCause, PowerChangeEvent, PowerChangedEvent, RegenModifierExpiredEvent,
+    RemoveRegenModifierEvent, ReviveCancelledEvent, ReviveEvent, ReviveStartedEvent,
+    SpendFailedEvent, SpendPowerEvent, StartDrainEvent, StartReviveEvent, StopDrainEvent,
 };
-pub use limits::{LimitType, PowerLimit, PowerLimits};
-pub use plugin::{PowerBundle, PowerSystem, PowerSystemPlugin};
+pub use grace::{GracePolicy, GraceWindow};
+pub use knockout::{KnockoutPolicy, KnockoutStage, KnockoutState};
+pub use leveling::{CustomCurve, LevelingCurve, LevelingCurves};
+pub use limits::{LimitStackOutcome, LimitStacking, LimitType, PowerLimit, PowerLimits};
+pub use plugin::{
+    EntityPower, PowerBundle, PowerCorePlugin, PowerReservation, PowerSystem, PowerSystemPlugin,
+};
+pub use regen::{RegenCeiling, RegenCurve, RegenModifier, RegenModifierKind, RegenModifiers};
+pub use resource::{Power, PowerDirection, PowerResource};
+pub use revive::{ReviveAttempt, ReviveCancelReason};
+pub use settings::{PowerClock, PowerSettings, PowerTimeSource};
+pub use snapshot::{PowerSnapshot, POWER_SNAPSHOT_VERSION};
+#[cfg(feature = "ui")]
+pub use ui::{spawn_power_bar_ui, NoPowerBarUI, PowerBarUI, PowerUiPlugin};
 
 pub mod prelude {
     pub use crate::{
-        components::{PowerBar, PowerLevel, PowerRegeneration},
+        components::{PowerBar, PowerLevel, PowerRegeneration, PrimaryPower},
+        drains::{DrainEndReason, PowerDrain, PowerDrains},
+        error::{FailureReason, PowerError},
         events::{
-            ApplyLimitEvent, KnockedOutEvent, LevelUpEvent, LiftLimitEvent, PowerChangeEvent,
-            ReviveEvent, SpendPowerEvent,
+            ApplyLimitEvent, ApplyRegenModifierEvent, CancelReviveEvent, ChangeMode, DownedEvent,
+            DrainEndedEvent, GainExperienceEvent, GraceEndedEvent, GraceStartedEvent,
+            KnockedOutEvent, LevelUpEvent, LiftLimitEvent, LimitAppliedEvent, LimitExpiredEvent,
+            LimitLiftedEvent, LimitRefreshedEvent, LimitRejectedEvent, OverloadClearedEvent,
+            OverloadedEvent, PowerChangeCause, PowerChangeEvent, PowerChangedEvent,
+            RegenModifierExpiredEvent, RemoveRegenModifierEvent, ReviveCancelledEvent, ReviveEvent,
+            ReviveStartedEvent, SpendFailedEvent, SpendPowerEvent, StartDrainEvent,
+            StartReviveEvent, StopDrainEvent,
+        },
+        grace::{GracePolicy, GraceWindow},
+        knockout::{KnockoutPolicy, KnockoutStage, KnockoutState},
+        leveling::{CustomCurve, LevelingCurve, LevelingCurves},
+        limits::{LimitStackOutcome, LimitStacking, LimitType, PowerLimit, PowerLimits},
+        plugin::{
+            EntityPower, PowerBundle, PowerCorePlugin, PowerReservation, PowerSystem,
+            PowerSystemPlugin,
         },
-        limits::{LimitType, PowerLimit, PowerLimits},
-        plugin::{PowerBundle, PowerSystem, PowerSystemPlugin},
+        regen::{RegenCeiling, RegenCurve, RegenModifier, RegenModifierKind, RegenModifiers},
+        resource::{Power, PowerDirection, PowerResource},
+        revive::{ReviveAttempt, ReviveCancelReason},
+        settings::{PowerClock, PowerSettings, PowerTimeSource},
+        snapshot::{PowerSnapshot, POWER_SNAPSHOT_VERSION},
     };
+
+    #[cfg(feature = "ui")]
+    pub use crate::ui::{spawn_power_bar_ui, NoPowerBarUI, PowerBarUI, PowerUiPlugin};
 }
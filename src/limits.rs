@@ -1,7 +1,11 @@
+use crate::resource::{Power, PowerResource};
 use bevy::prelude::*;
+use std::marker::PhantomData;
 
 /// Type of power limit - either fixed points or percentage
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LimitType {
     /// Fixed amount of power points
     Points(f32),
@@ -9,8 +13,49 @@ pub enum LimitType {
     Percentage(f32),
 }
 
+/// How a limit is applied when a limit with the same ID is already active
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LimitStacking {
+    /// Remove every active stack and apply the new limit in their place
+    #[default]
+    Replace,
+    /// Keep the active limit and restart its timer with the new duration
+    RefreshDuration,
+    /// Keep the active limit and add the new duration to the time left
+    ExtendDuration,
+    /// Apply the new limit as another stack, up to `max_stacks` in total
+    Stack { max_stacks: u32 },
+    /// Keep the active limit and drop the new one
+    Ignore,
+}
+
+/// What happened when a limit was added to `PowerLimits`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitStackOutcome {
+    /// No limit with this ID was active, the limit was added
+    Added,
+    /// Active stacks were replaced by the new limit
+    Replaced,
+    /// The active limit's timer was restarted
+    Refreshed,
+    /// The active limit's timer was extended
+    Extended,
+    /// The limit was added as a new stack
+    Stacked,
+    /// The limit was dropped (Ignore policy or max stacks reached)
+    Ignored,
+}
+
 /// Represents a power limit that reduces available power
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "PowerLimitData", into = "PowerLimitData")
+)]
 pub struct PowerLimit {
     /// Unique identifier for this limit
     pub id: u32,
@@ -24,6 +69,8 @@ pub struct PowerLimit {
     pub resets_cooldown: bool,
     /// Actual power value this limit takes
     pub power_value: f32,
+    /// How this limit stacks with active limits of the same ID
+    pub stacking: LimitStacking,
 }
 
 impl PowerLimit {
@@ -42,15 +89,27 @@ impl PowerLimit {
             duration: duration.map(|d| Timer::from_seconds(d, TimerMode::Once)),
             resets_cooldown,
             power_value: 0.0,
+            stacking: LimitStacking::default(),
         }
     }
 
-    /// Calculate the actual power value based on base max
-    pub fn calculate_value(&mut self, base_max: f32) {
-        self.power_value = match self.limit_type {
+    /// Set the stacking policy of this limit
+    pub fn with_stacking(mut self, stacking: LimitStacking) -> Self {
+        self.stacking = stacking;
+        self
+    }
+
+    /// Power value this limit takes for the given base max
+    pub fn value_for(&self, base_max: f32) -> f32 {
+        match self.limit_type {
             LimitType::Points(points) => points,
             LimitType::Percentage(percent) => base_max * (percent / 100.0),
-        };
+        }
+    }
+
+    /// Calculate the actual power value based on base max
+    pub fn calculate_value(&mut self, base_max: f32) {
+        self.power_value = self.value_for(base_max);
     }
 
     /// Update timer and check if limit should be removed
@@ -63,32 +122,169 @@ impl PowerLimit {
         }
     }
 
+    /// Seconds left before this limit expires (None if permanent)
+    pub fn remaining_secs(&self) -> Option<f32> {
+        self.duration.as_ref().map(|timer| timer.remaining_secs())
+    }
+
     /// Check if this limit is permanent (no timer)
     pub fn is_permanent(&self) -> bool {
         self.duration.is_none()
     }
 }
 
+/// Serialized form of a `PowerLimit`, storing the timer as plain seconds
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PowerLimitData {
+    id: u32,
+    limit_type: LimitType,
+    color: Color,
+    /// Total duration in seconds (None for permanent)
+    duration: Option<f32>,
+    /// Seconds already elapsed on the timer
+    #[serde(default)]
+    elapsed: f32,
+    resets_cooldown: bool,
+    power_value: f32,
+    #[serde(default)]
+    stacking: LimitStacking,
+}
+
+#[cfg(feature = "serde")]
+impl From<PowerLimit> for PowerLimitData {
+    fn from(limit: PowerLimit) -> Self {
+        Self {
+            id: limit.id,
+            limit_type: limit.limit_type,
+            color: limit.color,
+            duration: limit.duration.as_ref().map(|t| t.duration().as_secs_f32()),
+            elapsed: limit
+                .duration
+                .as_ref()
+                .map(|t| t.elapsed_secs())
+                .unwrap_or(0.0),
+            resets_cooldown: limit.resets_cooldown,
+            power_value: limit.power_value,
+            stacking: limit.stacking,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<PowerLimitData> for PowerLimit {
+    fn from(data: PowerLimitData) -> Self {
+        let mut limit = PowerLimit::new(
+            data.id,
+            data.limit_type,
+            data.color,
+            data.duration,
+            data.resets_cooldown,
+        );
+        if let Some(ref mut timer) = limit.duration {
+            timer.set_elapsed(std::time::Duration::from_secs_f32(data.elapsed));
+        }
+        limit.power_value = data.power_value;
+        limit.stacking = data.stacking;
+        limit
+    }
+}
+
 /// Bundle of active power limits
-#[derive(Component, Default, Debug)]
-pub struct PowerLimits {
+#[derive(Component, Reflect, Default, Debug, Clone)]
+#[reflect(Component, Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PowerLimits<R: PowerResource = Power> {
     pub limits: Vec<PowerLimit>,
+    /// Resource the limits apply to
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub resource: PhantomData<R>,
 }
 
-impl PowerLimits {
-    /// Add a new limit
-    pub fn add_limit(&mut self, mut limit: PowerLimit, base_max: f32) {
+impl<R: PowerResource> PowerLimits<R> {
+    /// Add a new limit, resolving duplicate IDs with the limit's stacking policy
+    pub fn add_limit(&mut self, mut limit: PowerLimit, base_max: f32) -> LimitStackOutcome {
         limit.calculate_value(base_max);
-        self.limits.push(limit);
+
+        let stacks = self.stack_count(limit.id);
+        if stacks == 0 {
+            self.limits.push(limit);
+            return LimitStackOutcome::Added;
+        }
+
+        match limit.stacking {
+            LimitStacking::Replace => {
+                self.remove_all(limit.id);
+                self.limits.push(limit);
+                LimitStackOutcome::Replaced
+            }
+            LimitStacking::RefreshDuration => {
+                for active in self.limits.iter_mut().filter(|l| l.id == limit.id) {
+                    active.duration = limit.duration.clone();
+                }
+                LimitStackOutcome::Refreshed
+            }
+            LimitStacking::ExtendDuration => {
+                let extra = limit.duration.as_ref().map(|t| t.duration());
+                for active in self.limits.iter_mut().filter(|l| l.id == limit.id) {
+                    match (active.duration.as_mut(), extra) {
+                        (Some(timer), Some(extra)) => {
+                            let total = timer.duration() + extra;
+                            timer.set_duration(total);
+                        }
+                        // A permanent limit stays permanent, a permanent extension makes it so
+                        (Some(_), None) => active.duration = None,
+                        (None, _) => {}
+                    }
+                }
+                LimitStackOutcome::Extended
+            }
+            LimitStacking::Stack { max_stacks } => {
+                if stacks < max_stacks as usize {
+                    self.limits.push(limit);
+                    LimitStackOutcome::Stacked
+                } else {
+                    LimitStackOutcome::Ignored
+                }
+            }
+            LimitStacking::Ignore => LimitStackOutcome::Ignored,
+        }
     }
 
-    /// Remove a limit by ID
-    pub fn remove_limit(&mut self, id: u32) -> bool {
-        if let Some(index) = self.limits.iter().position(|l| l.id == id) {
-            self.limits.remove(index);
-            true
-        } else {
-            false
+    /// Remove the oldest stack of a limit by ID, returns the removed limit
+    pub fn remove_limit(&mut self, id: u32) -> Option<PowerLimit> {
+        let index = self.limits.iter().position(|l| l.id == id)?;
+        Some(self.limits.remove(index))
+    }
+
+    /// Remove every stack of a limit by ID, returns the removed limits
+    pub fn remove_all(&mut self, id: u32) -> Vec<PowerLimit> {
+        let (removed, kept) = std::mem::take(&mut self.limits)
+            .into_iter()
+            .partition(|l| l.id == id);
+        self.limits = kept;
+        removed
+    }
+
+    /// Number of active stacks with the given ID
+    pub fn stack_count(&self, id: u32) -> usize {
+        self.limits.iter().filter(|l| l.id == id).count()
+    }
+
+    /// Total power reduction if `limit` were added, respecting its stacking policy
+    pub fn reduction_with(&self, limit: &PowerLimit, base_max: f32) -> f32 {
+        let mut preview = self.clone();
+        preview.add_limit(limit.clone(), base_max);
+        preview.total_reduction()
+    }
+
+    /// Recalculate the power value of every limit from the base max
+    ///
+    /// Percentage limits stay proportional to `base_max`.
+    pub fn recalculate(&mut self, base_max: f32) {
+        for limit in self.limits.iter_mut() {
+            limit.calculate_value(base_max);
         }
     }
 
@@ -97,20 +293,19 @@ impl PowerLimits {
         self.limits.iter().map(|l| l.power_value).sum()
     }
 
-    /// Update all limit timers and remove expired ones
-    pub fn update_timers(&mut self, delta: f32) -> Vec<u32> {
-        let mut removed_ids = Vec::new();
+    /// Update all limit timers and remove expired ones, returns the expired limits
+    pub fn update_timers(&mut self, delta: f32) -> Vec<PowerLimit> {
+        let mut expired = Vec::new();
 
-        self.limits.retain_mut(|limit| {
+        for mut limit in std::mem::take(&mut self.limits) {
             if limit.update(delta) {
-                removed_ids.push(limit.id);
-                false
+                expired.push(limit);
             } else {
-                true
+                self.limits.push(limit);
             }
-        });
+        }
 
-        removed_ids
+        expired
     }
 
     /// Check if any limit resets cooldown
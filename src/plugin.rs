@@ -1,30 +1,267 @@
 use crate::{
-    components::{PowerBar, PowerLevel, PowerRegeneration},
+    components::{PowerBar, PowerLevel, PowerRegeneration, PrimaryPower},
+    drains::{DrainEndReason, PowerDrain, PowerDrains},
+    error::{FailureReason, PowerError},
     events::*,
-    limits::PowerLimits,
+    grace::{GracePolicy, GraceWindow},
+    knockout::{KnockoutPolicy, KnockoutStage, KnockoutState},
+    leveling::{LevelingCurve, LevelingCurves},
+    limits::{LimitStacking, LimitType, PowerLimit, PowerLimits},
+    regen::{RegenCeiling, RegenCurve, RegenModifier, RegenModifierKind, RegenModifiers},
+    resource::{Power, PowerDirection, PowerResource},
+    revive::{ReviveAttempt, ReviveCancelReason, SettledRevives},
+    settings::{PowerClock, PowerSettings, PowerTimeSource},
     systems::*,
-    ui::{setup_power_ui, update_power_bar_ui},
 };
+use bevy::app::{FixedFirst, FixedLast, FixedPostUpdate, FixedPreUpdate};
+use bevy::ecs::query::QuerySingleError;
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use std::marker::PhantomData;
 
-/// Plugin for the power system
-pub struct PowerSystemPlugin;
+/// Plugin for the power system of resource `R`
+///
+/// Adds the `PowerCorePlugin` and, with the `ui` feature, the `PowerUiPlugin`.
+/// Add one plugin per resource, e.g. `PowerSystemPlugin::<Power>::default()`, or
+/// configure it with `PowerSystemPlugin::<Power> { spawn_default_ui: false, ..default() }`.
+pub struct PowerSystemPlugin<R: PowerResource = Power> {
+    /// Schedule the power logic runs in, the bars always update in `Update`
+    pub schedule: InternedScheduleLabel,
+    /// Whether every new `PowerBar` gets a bar widget, only used with the `ui` feature
+    pub spawn_default_ui: bool,
+    /// Regeneration inserted on new power bars spawned without a `PowerRegeneration`
+    pub default_regen: PowerRegeneration<R>,
+    /// Knockout policy of entities without a `KnockoutPolicy` component
+    pub default_knockout_policy: KnockoutPolicy,
+    /// Clock the power timers follow
+    pub time_source: PowerTimeSource,
+    /// Global leveling curves, shared by every resource
+    ///
+    /// `None` keeps a `LevelingCurves` resource already in the app, or the defaults.
+    pub leveling_curves: Option<LevelingCurves>,
+}
+
+impl<R: PowerResource> Default for PowerSystemPlugin<R> {
+    fn default() -> Self {
+        Self {
+            schedule: Update.intern(),
+            spawn_default_ui: true,
+            default_regen: PowerRegeneration::default(),
+            default_knockout_policy: KnockoutPolicy::default(),
+            time_source: PowerTimeSource::default(),
+            leveling_curves: None,
+        }
+    }
+}
 
-impl Plugin for PowerSystemPlugin {
+impl<R: PowerResource> PowerSystemPlugin<R> {
+    /// Run the power logic in `FixedUpdate` with `Time<Fixed>`
+    ///
+    /// Regeneration, drains and timers then advance by the same step however
+    /// the frames are split, e.g. for rollback netcode and replays.
+    pub fn fixed() -> Self {
+        Self {
+            schedule: FixedUpdate.intern(),
+            ..Default::default()
+        }
+    }
+}
+
+impl<R: PowerResource> Plugin for PowerSystemPlugin<R> {
     fn build(&self, app: &mut App) {
+        app.add_plugins(PowerCorePlugin::<R> {
+            schedule: self.schedule,
+            default_regen: self.default_regen.clone(),
+            default_knockout_policy: self.default_knockout_policy,
+            time_source: self.time_source,
+            leveling_curves: self.leveling_curves.clone(),
+        });
+
+        #[cfg(feature = "ui")]
+        app.add_plugins(crate::ui::PowerUiPlugin::<R> {
+            spawn_default_ui: self.spawn_default_ui,
+            resource: PhantomData,
+        });
+    }
+}
+
+/// Plugin for the power logic of resource `R`, without any UI
+///
+/// Only needs `MinimalPlugins`, e.g. for dedicated servers and headless simulations.
+pub struct PowerCorePlugin<R: PowerResource = Power> {
+    /// Schedule the power systems run in
+    pub schedule: InternedScheduleLabel,
+    /// Regeneration inserted on new power bars spawned without a `PowerRegeneration`
+    pub default_regen: PowerRegeneration<R>,
+    /// Knockout policy of entities without a `KnockoutPolicy` component
+    pub default_knockout_policy: KnockoutPolicy,
+    /// Clock the power timers follow
+    pub time_source: PowerTimeSource,
+    /// Global leveling curves, shared by every resource
+    ///
+    /// `None` keeps a `LevelingCurves` resource already in the app, or the defaults.
+    pub leveling_curves: Option<LevelingCurves>,
+}
+
+impl<R: PowerResource> Default for PowerCorePlugin<R> {
+    fn default() -> Self {
+        Self {
+            schedule: Update.intern(),
+            default_regen: PowerRegeneration::default(),
+            default_knockout_policy: KnockoutPolicy::default(),
+            time_source: PowerTimeSource::default(),
+            leveling_curves: None,
+        }
+    }
+}
+
+impl<R: PowerResource> PowerCorePlugin<R> {
+    /// Run the power logic in `FixedUpdate` with `Time<Fixed>`
+    pub fn fixed() -> Self {
+        Self {
+            schedule: FixedUpdate.intern(),
+            ..Default::default()
+        }
+    }
+}
+
+impl<R: PowerResource> Plugin for PowerCorePlugin<R> {
+    fn build(&self, app: &mut App) {
+        // Frame-based clocks would advance by a whole frame on every fixed tick
+        let fixed_schedules = [
+            FixedFirst.intern(),
+            FixedPreUpdate.intern(),
+            FixedUpdate.intern(),
+            FixedPostUpdate.intern(),
+            FixedLast.intern(),
+        ];
+        assert!(
+            self.time_source == PowerTimeSource::Schedule
+                || !fixed_schedules.contains(&self.schedule),
+            "PowerTimeSource::{:?} can't drive power logic in a fixed schedule, use PowerTimeSource::Schedule",
+            self.time_source
+        );
+
+        // Project-wide defaults and the clock the power timers follow
+        app.insert_resource(PowerSettings::<R> {
+            default_regen: self.default_regen.clone(),
+            default_knockout_policy: self.default_knockout_policy,
+            time_source: self.time_source,
+        })
+        .init_resource::<Time<PowerClock<R>>>()
+        .init_resource::<SettledRevives<R>>();
+
         // Register messages
-        app.add_message::<SpendPowerEvent>()
-            .add_message::<PowerChangeEvent>()
-            .add_message::<ApplyLimitEvent>()
-            .add_message::<LiftLimitEvent>()
-            .add_message::<KnockedOutEvent>()
-            .add_message::<ReviveEvent>()
-            .add_message::<LevelUpEvent>();
+        app.add_message::<SpendPowerEvent<R>>()
+            .add_message::<PowerChangeEvent<R>>()
+            .add_message::<ApplyLimitEvent<R>>()
+            .add_message::<LiftLimitEvent<R>>()
+            .add_message::<KnockedOutEvent<R>>()
+            .add_message::<DownedEvent<R>>()
+            .add_message::<OverloadedEvent<R>>()
+            .add_message::<OverloadClearedEvent<R>>()
+            .add_message::<StartReviveEvent<R>>()
+            .add_message::<CancelReviveEvent<R>>()
+            .add_message::<ReviveStartedEvent<R>>()
+            .add_message::<ReviveCancelledEvent<R>>()
+            .add_message::<ReviveEvent<R>>()
+            .add_message::<GraceStartedEvent<R>>()
+            .add_message::<GraceEndedEvent<R>>()
+            .add_message::<GainExperienceEvent<R>>()
+            .add_message::<LevelUpEvent<R>>()
+            .add_message::<LimitAppliedEvent<R>>()
+            .add_message::<LimitRefreshedEvent<R>>()
+            .add_message::<LimitExpiredEvent<R>>()
+            .add_message::<LimitLiftedEvent<R>>()
+            .add_message::<PowerChangedEvent<R>>()
+            .add_message::<SpendFailedEvent<R>>()
+            .add_message::<LimitRejectedEvent<R>>()
+            .add_message::<StartDrainEvent<R>>()
+            .add_message::<StopDrainEvent<R>>()
+            .add_message::<DrainEndedEvent<R>>()
+            .add_message::<ApplyRegenModifierEvent<R>>()
+            .add_message::<RemoveRegenModifierEvent<R>>()
+            .add_message::<RegenModifierExpiredEvent<R>>();
+
+        // Register types for reflection, scenes and tooling
+        app.register_type::<PowerBar<R>>()
+            .register_type::<PowerLevel<R>>()
+            .register_type::<PowerRegeneration<R>>()
+            .register_type::<RegenCurve>()
+            .register_type::<RegenCeiling>()
+            .register_type::<RegenModifiers<R>>()
+            .register_type::<RegenModifier>()
+            .register_type::<RegenModifierKind>()
+            .register_type::<PrimaryPower>()
+            .register_type::<KnockoutPolicy>()
+            .register_type::<KnockoutStage>()
+            .register_type::<KnockoutState<R>>()
+            .register_type::<ReviveAttempt<R>>()
+            .register_type::<ReviveCancelReason>()
+            .register_type::<GracePolicy>()
+            .register_type::<GraceWindow<R>>()
+            .register_type::<PowerLimits<R>>()
+            .register_type::<PowerLimit>()
+            .register_type::<LimitType>()
+            .register_type::<LimitStacking>()
+            .register_type::<PowerDrains<R>>()
+            .register_type::<PowerDrain>()
+            .register_type::<DrainEndReason>()
+            .register_type::<PowerDirection>()
+            .register_type::<LevelingCurve>()
+            .register_type::<LevelingCurves>()
+            .register_type::<PowerSettings<R>>()
+            .register_type::<PowerTimeSource>()
+            .register_type::<SpendPowerEvent<R>>()
+            .register_type::<PowerChangeEvent<R>>()
+            .register_type::<ApplyLimitEvent<R>>()
+            .register_type::<LiftLimitEvent<R>>()
+            .register_type::<KnockedOutEvent<R>>()
+            .register_type::<DownedEvent<R>>()
+            .register_type::<OverloadedEvent<R>>()
+            .register_type::<OverloadClearedEvent<R>>()
+            .register_type::<StartReviveEvent<R>>()
+            .register_type::<CancelReviveEvent<R>>()
+            .register_type::<ReviveStartedEvent<R>>()
+            .register_type::<ReviveCancelledEvent<R>>()
+            .register_type::<ReviveEvent<R>>()
+            .register_type::<GraceStartedEvent<R>>()
+            .register_type::<GraceEndedEvent<R>>()
+            .register_type::<GainExperienceEvent<R>>()
+            .register_type::<LevelUpEvent<R>>()
+            .register_type::<LimitAppliedEvent<R>>()
+            .register_type::<LimitRefreshedEvent<R>>()
+            .register_type::<LimitExpiredEvent<R>>()
+            .register_type::<LimitLiftedEvent<R>>()
+            .register_type::<PowerChangeCause>()
+            .register_type::<PowerChangedEvent<R>>()
+            .register_type::<ChangeMode>()
+            .register_type::<FailureReason>()
+            .register_type::<SpendFailedEvent<R>>()
+            .register_type::<LimitRejectedEvent<R>>()
+            .register_type::<StartDrainEvent<R>>()
+            .register_type::<StopDrainEvent<R>>()
+            .register_type::<DrainEndedEvent<R>>()
+            .register_type::<ApplyRegenModifierEvent<R>>()
+            .register_type::<RemoveRegenModifierEvent<R>>()
+            .register_type::<RegenModifierExpiredEvent<R>>();
+
+        // Global leveling curves, kept if already inserted by the app unless configured
+        match &self.leveling_curves {
+            Some(curves) => app.insert_resource(curves.clone()),
+            None => app.init_resource::<LevelingCurves>(),
+        };
+
+        // Give new power bars the default regeneration unless spawned with one
+        app.add_observer(insert_default_regen::<R>);
+
+        // Cancel revive attempts removed from outside, e.g. with a despawned target
+        app.add_observer(cancel_removed_revive::<R>);
 
         // Configure system sets
         app.configure_sets(
-            Update,
+            self.schedule,
             (
                 PowerSystemSet::Input,
                 PowerSystemSet::Update,
@@ -33,47 +270,75 @@ impl Plugin for PowerSystemPlugin {
                 .chain(),
         );
 
-        // Add startup systems
-        app.add_systems(Startup, setup_power_ui);
-
         // Add update systems in proper order
         app.add_systems(
-            Update,
+            self.schedule,
             (
                 // Input/Event handling
                 (
-                    handle_spend_power,
-                    handle_power_change,
-                    handle_apply_limit,
-                    handle_lift_limit,
-                    handle_revive,
+                    tick_power_clock::<R>,
+                    init_power_level::<R>,
+                    handle_spend_power::<R>,
+                    handle_power_change::<R>,
+                    handle_apply_limit::<R>,
+                    handle_lift_limit::<R>,
+                    handle_revive::<R>,
+                    handle_start_revive::<R>,
+                    handle_cancel_revive::<R>,
+                    handle_gain_experience::<R>,
+                    handle_start_drain::<R>,
+                    handle_stop_drain::<R>,
+                    handle_apply_regen_modifier::<R>,
+                    handle_remove_regen_modifier::<R>,
                 )
                     .in_set(PowerSystemSet::Input),
                 // Core updates
                 (
-                    regenerate_power,
-                    update_limit_timers,
-                    detect_knockout,
-                    handle_level_up,
+                    update_regen_modifier_timers::<R>,
+                    regenerate_power::<R>,
+                    tick_power_drains::<R>,
+                    update_limit_timers::<R>,
+                    update_revive_attempts::<R>,
+                    update_knockout_timers::<R>,
+                    update_grace_windows::<R>,
+                    handle_level_up::<R>,
+                    sync_power_max::<R>,
+                    detect_knockout::<R>,
+                    detect_overload::<R>,
                 )
+                    .chain()
                     .in_set(PowerSystemSet::Update),
-                // UI updates
-                update_power_bar_ui.in_set(PowerSystemSet::UI),
             ),
         );
     }
 }
 
-/// Bundle for spawning an entity with power components
-#[derive(Bundle, Default)]
-pub struct PowerBundle {
-    pub power_bar: PowerBar,
-    pub power_level: PowerLevel,
-    pub power_regeneration: PowerRegeneration,
-    pub power_limits: PowerLimits,
+/// Bundle for spawning an entity with power components of resource `R`
+///
+/// Carries no `PowerRegeneration`, the bar gets the plugin's `default_regen`
+/// unless one is spawned alongside it, e.g. by `custom`.
+#[derive(Bundle)]
+pub struct PowerBundle<R: PowerResource = Power> {
+    pub power_bar: PowerBar<R>,
+    pub power_level: PowerLevel<R>,
+    pub power_limits: PowerLimits<R>,
+    pub power_drains: PowerDrains<R>,
+    pub regen_modifiers: RegenModifiers<R>,
+}
+
+impl<R: PowerResource> Default for PowerBundle<R> {
+    fn default() -> Self {
+        Self {
+            power_bar: PowerBar::default(),
+            power_level: PowerLevel::default(),
+            power_limits: PowerLimits::default(),
+            power_drains: PowerDrains::default(),
+            regen_modifiers: RegenModifiers::default(),
+        }
+    }
 }
 
-impl PowerBundle {
+impl<R: PowerResource> PowerBundle<R> {
     /// Create a new power bundle with default values
     pub fn new() -> Self {
         Self::default()
@@ -87,67 +352,188 @@ impl PowerBundle {
         }
     }
 
-    /// Create a power bundle with custom settings
+    /// Create a power bundle with custom max power and its own regeneration
     pub fn custom(
         max_power: f32,
         regen_delay: f32,
         base_regen_rate: f32,
         max_regen_rate: f32,
-    ) -> Self {
-        Self {
-            power_bar: PowerBar::new(max_power),
-            power_level: PowerLevel::default(),
-            power_regeneration: PowerRegeneration {
+    ) -> (Self, PowerRegeneration<R>) {
+        (
+            Self::with_max_power(max_power),
+            PowerRegeneration {
                 regen_delay,
                 base_rate: base_regen_rate,
                 max_rate: max_regen_rate,
                 ramp_speed: 2.0,
                 ..Default::default()
             },
-            power_limits: PowerLimits::default(),
-        }
+        )
     }
 }
 
 /// System parameters for convenient power system access
+///
+/// Methods on `PowerSystem` itself target the primary power entity (see
+/// [`PrimaryPower`]). Use [`PowerSystem::entity`] to target any other entity.
+#[allow(clippy::type_complexity)]
 #[derive(SystemParam)]
-pub struct PowerSystem<'w, 's> {
-    pub spend_events: MessageWriter<'w, SpendPowerEvent>,
-    pub change_events: MessageWriter<'w, PowerChangeEvent>,
-    pub limit_events: MessageWriter<'w, ApplyLimitEvent>,
-    pub lift_events: MessageWriter<'w, LiftLimitEvent>,
-    pub revive_events: MessageWriter<'w, ReviveEvent>,
-    pub power_query: Query<'w, 's, (Entity, &'static mut PowerBar, Option<&'static PowerLimits>)>,
+pub struct PowerSystem<'w, 's, R: PowerResource = Power> {
+    pub spend_events: MessageWriter<'w, SpendPowerEvent<R>>,
+    pub change_events: MessageWriter<'w, PowerChangeEvent<R>>,
+    pub limit_events: MessageWriter<'w, ApplyLimitEvent<R>>,
+    pub lift_events: MessageWriter<'w, LiftLimitEvent<R>>,
+    pub revive_events: MessageWriter<'w, ReviveEvent<R>>,
+    pub start_revive_events: MessageWriter<'w, StartReviveEvent<R>>,
+    pub cancel_revive_events: MessageWriter<'w, CancelReviveEvent<R>>,
+    pub experience_events: MessageWriter<'w, GainExperienceEvent<R>>,
+    pub spend_failed_events: MessageWriter<'w, SpendFailedEvent<R>>,
+    pub limit_rejected_events: MessageWriter<'w, LimitRejectedEvent<R>>,
+    pub start_drain_events: MessageWriter<'w, StartDrainEvent<R>>,
+    pub stop_drain_events: MessageWriter<'w, StopDrainEvent<R>>,
+    pub regen_modifier_events: MessageWriter<'w, ApplyRegenModifierEvent<R>>,
+    pub remove_regen_modifier_events: MessageWriter<'w, RemoveRegenModifierEvent<R>>,
+    pub power_query: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static mut PowerBar<R>,
+            Option<&'static PowerLimits<R>>,
+        ),
+    >,
+    pub primary_query: Query<'w, 's, Entity, (With<PrimaryPower>, With<PowerBar<R>>)>,
+    pub grace_query: Query<'w, 's, &'static GraceWindow<R>>,
 }
 
-impl<'w, 's> PowerSystem<'w, 's> {
-    /// Get the entity with PowerBar component (assumes single entity)
-    fn get_power_entity(&self) -> Option<Entity> {
-        self.power_query.iter().next().map(|(entity, _, _)| entity)
+impl<'w, 's, R: PowerResource> PowerSystem<'w, 's, R> {
+    /// Get the primary power entity
+    ///
+    /// Returns the entity marked with `PrimaryPower`, or the only entity with a
+    /// `PowerBar` when none is marked. Several marked entities are an error
+    /// rather than an arbitrary pick.
+    pub fn primary_entity(&self) -> Result<Entity, PowerError> {
+        match self.primary_query.single() {
+            Ok(entity) => Ok(entity),
+            Err(QuerySingleError::MultipleEntities(_)) => Err(PowerError::AmbiguousPrimaryEntity),
+            Err(QuerySingleError::NoEntities(_)) => self
+                .power_query
+                .single()
+                .map(|(entity, _, _)| entity)
+                .map_err(|_| PowerError::NoPrimaryEntity),
+        }
+    }
+
+    /// Access the power API for a specific entity
+    pub fn entity(&mut self, entity: Entity) -> EntityPower<'_, 'w, 's, R> {
+        EntityPower {
+            system: self,
+            entity,
+        }
+    }
+
+    /// Access the power API for the primary power entity
+    pub fn primary(&mut self) -> Result<EntityPower<'_, 'w, 's, R>, PowerError> {
+        let entity = self.primary_entity()?;
+        Ok(self.entity(entity))
+    }
+
+    /// Check if an entity can afford to spend the specified amount of power
+    pub fn can_afford_for(&self, entity: Entity, amount: f32) -> Result<bool, PowerError> {
+        match self.check_spend_for(entity, amount) {
+            Ok(()) => Ok(true),
+            Err(PowerError::MissingPowerBar(entity)) => Err(PowerError::MissingPowerBar(entity)),
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// Check if an entity can spend the specified amount of power, with the reason if not
+    pub fn check_spend_for(&self, entity: Entity, amount: f32) -> Result<(), PowerError> {
+        let (_, power_bar, _) = self
+            .power_query
+            .get(entity)
+            .map_err(|_| PowerError::MissingPowerBar(entity))?;
+        if self.blocks_spends(entity) {
+            return Err(PowerError::InGrace);
+        }
+        power_bar.check_spend(amount).map_err(spend_error)
+    }
+
+    /// Check if an entity is in a grace window that rejects spends
+    fn blocks_spends(&self, entity: Entity) -> bool {
+        self.grace_query
+            .get(entity)
+            .is_ok_and(|grace| grace.blocks_spends)
     }
 
     /// Check if the power entity can afford to spend the specified amount of power
     pub fn can_afford(&self, amount: f32) -> bool {
-        if let Some(entity) = self.get_power_entity() {
-            if let Ok((_, power_bar, _)) = self.power_query.get(entity) {
-                return !power_bar.is_knocked_out && power_bar.current > amount;
-            }
+        self.primary_entity()
+            .and_then(|entity| self.can_afford_for(entity, amount))
+            .unwrap_or(false)
+    }
+
+    /// Try to spend power
+    ///
+    /// Sends a `SpendFailedEvent` and returns the reason if the spend is rejected.
+    pub fn try_spend(&mut self, amount: f32) -> Result<(), PowerError> {
+        self.primary()?.try_spend(amount)
+    }
+
+    /// Reserve power on the primary entity, see [`EntityPower::reserve`]
+    pub fn reserve(&mut self, amount: f32) -> Result<PowerReservation<R>, PowerError> {
+        self.primary()?.reserve(amount)
+    }
+
+    /// Spend reserved power
+    ///
+    /// The reserved amount stays held back until the spend is processed.
+    pub fn commit(&mut self, reservation: PowerReservation<R>) {
+        self.spend_events.write(SpendPowerEvent::reserved(
+            reservation.entity,
+            reservation.amount,
+        ));
+    }
+
+    /// Give reserved power back without spending it
+    pub fn cancel(&mut self, reservation: PowerReservation<R>) {
+        if let Ok((_, mut power_bar, _)) = self.power_query.get_mut(reservation.entity) {
+            power_bar.release(reservation.amount);
         }
-        false
     }
 
-    /// Try to spend power, returns true if successful
-    pub fn try_spend(&mut self, amount: f32) -> bool {
-        if let Some(entity) = self.get_power_entity() {
-            if self.can_afford(amount) {
-                self.spend_events.write(SpendPowerEvent { entity, amount });
-                return true;
+    /// Release all power reserved on the primary entity, see [`EntityPower::clear_reservations`]
+    pub fn clear_reservations(&mut self) -> Result<(), PowerError> {
+        self.primary()?.clear_reservations()
+    }
+
+    /// Spend several costs at once, only if every one of them can be paid
+    ///
+    /// Costs may target different entities or the same entity more than once.
+    /// If any cost is rejected nothing is spent, a `SpendFailedEvent` is sent
+    /// for that cost and its reason is returned.
+    pub fn try_spend_all(&mut self, costs: &[(Entity, f32)]) -> Result<(), PowerError> {
+        let mut reservations = Vec::with_capacity(costs.len());
+        for &(entity, amount) in costs {
+            match self.entity(entity).reserve(amount) {
+                Ok(reservation) => reservations.push(reservation),
+                Err(err) => {
+                    for reservation in reservations {
+                        self.cancel(reservation);
+                    }
+                    return Err(err);
+                }
             }
         }
-        false
+        for reservation in reservations {
+            self.commit(reservation);
+        }
+        Ok(())
     }
 
-    /// Try to apply a points-based limit, returns true if successful
+    /// Try to apply a points-based limit
+    ///
+    /// Sends a `LimitRejectedEvent` and returns the reason if the limit is rejected.
     pub fn try_limit_points(
         &mut self,
         id: u32,
@@ -155,33 +541,14 @@ impl<'w, 's> PowerSystem<'w, 's> {
         color: Color,
         duration: Option<f32>,
         resets_cooldown: bool,
-    ) -> bool {
-        if let Some(entity) = self.get_power_entity() {
-            // Check if applying this limit would cause a knockout
-            if let Ok((_, power_bar, limits)) = self.power_query.get(entity) {
-                let total_current_reduction = limits.map(|l| l.total_reduction()).unwrap_or(0.0);
-                let new_total_reduction = total_current_reduction + points;
-                let new_max = (power_bar.base_max - new_total_reduction).max(0.0);
-                let new_current = power_bar.current.min(new_max);
-
-                // Only apply if it won't cause knockout (max > 0 and current > 0)
-                if new_max > 0.0 && new_current > 0.0 {
-                    self.limit_events.write(ApplyLimitEvent::points(
-                        entity,
-                        id,
-                        points,
-                        color,
-                        duration,
-                        resets_cooldown,
-                    ));
-                    return true;
-                }
-            }
-        }
-        false
+    ) -> Result<(), PowerError> {
+        self.primary()?
+            .try_limit_points(id, points, color, duration, resets_cooldown)
     }
 
-    /// Try to apply a percentage-based limit, returns true if successful
+    /// Try to apply a percentage-based limit
+    ///
+    /// Sends a `LimitRejectedEvent` and returns the reason if the limit is rejected.
     pub fn try_limit_percentage(
         &mut self,
         id: u32,
@@ -189,45 +556,29 @@ impl<'w, 's> PowerSystem<'w, 's> {
         color: Color,
         duration: Option<f32>,
         resets_cooldown: bool,
-    ) -> bool {
-        if let Some(entity) = self.get_power_entity() {
-            // Check if applying this limit would cause a knockout
-            if let Ok((_, power_bar, limits)) = self.power_query.get(entity) {
-                let percentage_points = power_bar.base_max * (percentage / 100.0);
-                let total_current_reduction = limits.map(|l| l.total_reduction()).unwrap_or(0.0);
-                let new_total_reduction = total_current_reduction + percentage_points;
-                let new_max = (power_bar.base_max - new_total_reduction).max(0.0);
-                let new_current = power_bar.current.min(new_max);
-
-                // Only apply if it won't cause knockout (max > 0 and current > 0)
-                if new_max > 0.0 && new_current > 0.0 {
-                    self.limit_events.write(ApplyLimitEvent::percentage(
-                        entity,
-                        id,
-                        percentage,
-                        color,
-                        duration,
-                        resets_cooldown,
-                    ));
-                    return true;
-                }
-            }
-        }
-        false
+    ) -> Result<(), PowerError> {
+        self.primary()?
+            .try_limit_percentage(id, percentage, color, duration, resets_cooldown)
     }
 
     /// Spend power (always sends event, may fail)
     pub fn spend(&mut self, amount: f32) {
-        if let Some(entity) = self.get_power_entity() {
-            self.spend_events.write(SpendPowerEvent { entity, amount });
+        if let Ok(mut power) = self.primary() {
+            let _ = power.spend(amount);
         }
     }
 
-    /// Change power (add or subtract)
+    /// Change power (add or subtract), never knocking the entity out
     pub fn change(&mut self, amount: f32) {
-        if let Some(entity) = self.get_power_entity() {
-            self.change_events
-                .write(PowerChangeEvent { entity, amount });
+        if let Ok(mut power) = self.primary() {
+            let _ = power.change(amount);
+        }
+    }
+
+    /// Drain power down to zero, knocking the entity out if it runs out
+    pub fn drain(&mut self, amount: f32) {
+        if let Ok(mut power) = self.primary() {
+            let _ = power.drain(amount);
         }
     }
 
@@ -240,15 +591,8 @@ impl<'w, 's> PowerSystem<'w, 's> {
         duration: Option<f32>,
         resets_cooldown: bool,
     ) {
-        if let Some(entity) = self.get_power_entity() {
-            self.limit_events.write(ApplyLimitEvent::points(
-                entity,
-                id,
-                points,
-                color,
-                duration,
-                resets_cooldown,
-            ));
+        if let Ok(mut power) = self.primary() {
+            let _ = power.limit_points(id, points, color, duration, resets_cooldown);
         }
     }
 
@@ -261,35 +605,494 @@ impl<'w, 's> PowerSystem<'w, 's> {
         duration: Option<f32>,
         resets_cooldown: bool,
     ) {
-        if let Some(entity) = self.get_power_entity() {
-            self.limit_events.write(ApplyLimitEvent::percentage(
-                entity,
-                id,
-                percentage,
-                color,
-                duration,
-                resets_cooldown,
-            ));
+        if let Ok(mut power) = self.primary() {
+            let _ = power.limit_percentage(id, percentage, color, duration, resets_cooldown);
         }
     }
 
-    /// Lift a limit
+    /// Lift every stack of a limit
     pub fn lift(&mut self, limit_id: u32) {
-        if let Some(entity) = self.get_power_entity() {
-            self.lift_events.write(LiftLimitEvent {
-                entity,
-                id: limit_id,
-            });
+        if let Ok(mut power) = self.primary() {
+            let _ = power.lift(limit_id);
+        }
+    }
+
+    /// Lift only the oldest stack of a limit
+    pub fn lift_one(&mut self, limit_id: u32) {
+        if let Ok(mut power) = self.primary() {
+            let _ = power.lift_one(limit_id);
+        }
+    }
+
+    /// Start a safe drain of `rate_per_sec` that pauses regeneration
+    pub fn start_drain(&mut self, id: u32, rate_per_sec: f32) {
+        if let Ok(mut power) = self.primary() {
+            let _ = power.start_drain(id, rate_per_sec);
+        }
+    }
+
+    /// Start a configured drain
+    pub fn start_drain_with(&mut self, drain: PowerDrain) {
+        if let Ok(mut power) = self.primary() {
+            let _ = power.start_drain_with(drain);
+        }
+    }
+
+    /// Stop a drain
+    pub fn stop_drain(&mut self, id: u32) {
+        if let Ok(mut power) = self.primary() {
+            let _ = power.stop_drain(id);
+        }
+    }
+
+    /// Add a regeneration modifier, replacing any modifier with the same ID
+    pub fn modify_regen(&mut self, id: u32, kind: RegenModifierKind, duration: Option<f32>) {
+        if let Ok(mut power) = self.primary() {
+            let _ = power.modify_regen(id, kind, duration);
+        }
+    }
+
+    /// Block regeneration until the modifier is removed or expires
+    pub fn block_regen(&mut self, id: u32, duration: Option<f32>) {
+        self.modify_regen(id, RegenModifierKind::Block, duration);
+    }
+
+    /// Remove a regeneration modifier
+    pub fn remove_regen_modifier(&mut self, id: u32) {
+        if let Ok(mut power) = self.primary() {
+            let _ = power.remove_regen_modifier(id);
         }
     }
 
     /// Revive a knocked out entity
     pub fn revive(&mut self, power_amount: f32) {
-        if let Some(entity) = self.get_power_entity() {
-            self.revive_events.write(ReviveEvent {
-                entity,
-                power_amount,
-            });
+        if let Ok(mut power) = self.primary() {
+            let _ = power.revive(power_amount);
+        }
+    }
+
+    /// Start reviving a knocked out entity with the primary entity as reviver
+    pub fn start_revive(&mut self, target: Entity, duration: f32, power_amount: f32, cost: f32) {
+        if let Ok(mut power) = self.primary() {
+            let _ = power.start_revive(target, duration, power_amount, cost);
+        }
+    }
+
+    /// Cancel the revive attempt on a target
+    pub fn cancel_revive(&mut self, target: Entity) {
+        self.cancel_revive_events
+            .write(CancelReviveEvent::new(target));
+    }
+
+    /// Grant experience
+    pub fn gain_experience(&mut self, amount: f32) {
+        if let Ok(mut power) = self.primary() {
+            let _ = power.gain_experience(amount);
         }
     }
 }
+
+/// Map a spend rejection to the error returned by the API
+fn spend_error(reason: FailureReason) -> PowerError {
+    match reason {
+        FailureReason::KnockedOut => PowerError::KnockedOut,
+        FailureReason::Overloaded => PowerError::Overloaded,
+        _ => PowerError::InsufficientPower,
+    }
+}
+
+/// Power held back on an entity, created by [`EntityPower::reserve`]
+///
+/// Pass it to [`PowerSystem::commit`] to spend the power or to
+/// [`PowerSystem::cancel`] to give it back. Dropping it keeps the power reserved
+/// until [`EntityPower::clear_reservations`] releases everything held on the entity.
+#[derive(Debug)]
+#[must_use = "reserved power stays held back until committed or cancelled"]
+pub struct PowerReservation<R: PowerResource = Power> {
+    entity: Entity,
+    amount: f32,
+    resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> PowerReservation<R> {
+    /// The entity the power is reserved on
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// The reserved amount
+    pub fn amount(&self) -> f32 {
+        self.amount
+    }
+}
+
+/// Power API scoped to a single entity, created by [`PowerSystem::entity`]
+///
+/// Every method returns `PowerError::MissingPowerBar` if the entity has no `PowerBar`.
+pub struct EntityPower<'a, 'w, 's, R: PowerResource = Power> {
+    system: &'a mut PowerSystem<'w, 's, R>,
+    entity: Entity,
+}
+
+impl<'a, 'w, 's, R: PowerResource> EntityPower<'a, 'w, 's, R> {
+    /// The entity this API targets
+    pub fn id(&self) -> Entity {
+        self.entity
+    }
+
+    /// Read the entity's power bar
+    pub fn power_bar(&self) -> Result<&PowerBar<R>, PowerError> {
+        self.system
+            .power_query
+            .get(self.entity)
+            .map(|(_, power_bar, _)| power_bar)
+            .map_err(|_| PowerError::MissingPowerBar(self.entity))
+    }
+
+    /// Check if the entity can afford to spend the specified amount of power
+    pub fn can_afford(&self, amount: f32) -> Result<bool, PowerError> {
+        self.system.can_afford_for(self.entity, amount)
+    }
+
+    /// Try to spend power
+    ///
+    /// The amount is reserved right away, so later spends in the same frame
+    /// see it. Sends a `SpendFailedEvent` and returns the reason if the spend
+    /// is rejected.
+    pub fn try_spend(&mut self, amount: f32) -> Result<(), PowerError> {
+        let reservation = self.reserve(amount)?;
+        self.system.commit(reservation);
+        Ok(())
+    }
+
+    /// Hold back power until the reservation is committed or cancelled
+    ///
+    /// Reserved power cannot be spent by anything else in the meantime. Sends
+    /// a `SpendFailedEvent` and returns the reason if the amount can't be
+    /// reserved.
+    pub fn reserve(&mut self, amount: f32) -> Result<PowerReservation<R>, PowerError> {
+        let entity = self.entity;
+        let in_grace = self.system.blocks_spends(entity);
+        let result = match self.system.power_query.get_mut(entity) {
+            Ok(_) if in_grace => Err(PowerError::InGrace),
+            Ok((_, mut power_bar, _)) => power_bar.reserve(amount).map_err(spend_error),
+            Err(_) => Err(PowerError::MissingPowerBar(entity)),
+        };
+        if let Err(err) = result {
+            if let Some(reason) = err.reason() {
+                self.system.spend_failed_events.write(SpendFailedEvent {
+                    entity,
+                    amount,
+                    reason,
+                    resource: PhantomData,
+                });
+            }
+            return Err(err);
+        }
+        Ok(PowerReservation {
+            entity,
+            amount,
+            resource: PhantomData,
+        })
+    }
+
+    /// Release all power reserved on the entity
+    ///
+    /// Recovers power held by dropped `PowerReservation`s. This also releases
+    /// spends committed this frame and the costs of revives the entity is
+    /// channelling, so call it when nothing else is holding power, e.g. when
+    /// the entity leaves combat.
+    pub fn clear_reservations(&mut self) -> Result<(), PowerError> {
+        let (_, mut power_bar, _) = self
+            .system
+            .power_query
+            .get_mut(self.entity)
+            .map_err(|_| PowerError::MissingPowerBar(self.entity))?;
+        power_bar.reserved = 0.0;
+        Ok(())
+    }
+
+    /// Check whether a limit can be applied right away without a knockout
+    ///
+    /// Grace windows defer limits and apply them unchecked when they end, so a
+    /// checked limit is rejected during one instead.
+    fn check_limit(&self, event: &ApplyLimitEvent<R>) -> Result<(), PowerError> {
+        let (_, power_bar, limits) = self
+            .system
+            .power_query
+            .get(self.entity)
+            .map_err(|_| PowerError::MissingPowerBar(self.entity))?;
+        let limit = PowerLimit::new(
+            event.id,
+            event.limit_type,
+            event.color,
+            event.duration,
+            event.resets_cooldown,
+        )
+        .with_stacking(event.stacking);
+        let new_total_reduction = limits
+            .cloned()
+            .unwrap_or_default()
+            .reduction_with(&limit, power_bar.base_max);
+        let new_max = (power_bar.base_max - new_total_reduction).max(0.0);
+        let new_current = power_bar.current.min(new_max);
+
+        // Only apply if it won't cause knockout (max > 0 and current > 0) or an overload
+        match R::DIRECTION {
+            _ if power_bar.is_knocked_out => Err(PowerError::KnockedOut),
+            _ if power_bar.is_overloaded => Err(PowerError::Overloaded),
+            _ if self.system.grace_query.contains(self.entity) => Err(PowerError::InGrace),
+            PowerDirection::Depleting if new_max > 0.0 && new_current > 0.0 => Ok(()),
+            PowerDirection::Depleting => Err(PowerError::WouldKnockOut),
+            PowerDirection::Accumulating if new_current < new_max => Ok(()),
+            PowerDirection::Accumulating => Err(PowerError::WouldOverload),
+        }
+    }
+
+    /// Try to apply a limit
+    ///
+    /// The event's entity is replaced by this API's entity. Sends a
+    /// `LimitRejectedEvent` and returns the reason if the limit is rejected.
+    pub fn try_apply_limit(&mut self, mut event: ApplyLimitEvent<R>) -> Result<(), PowerError> {
+        event.entity = self.entity;
+        if let Err(err) = self.check_limit(&event) {
+            if let Some(reason) = err.reason() {
+                self.system.limit_rejected_events.write(LimitRejectedEvent {
+                    entity: self.entity,
+                    id: event.id,
+                    limit_type: event.limit_type,
+                    reason,
+                    resource: PhantomData,
+                });
+            }
+            return Err(err);
+        }
+        self.system.limit_events.write(event);
+        Ok(())
+    }
+
+    /// Try to apply a points-based limit
+    pub fn try_limit_points(
+        &mut self,
+        id: u32,
+        points: f32,
+        color: Color,
+        duration: Option<f32>,
+        resets_cooldown: bool,
+    ) -> Result<(), PowerError> {
+        self.try_apply_limit(ApplyLimitEvent::points(
+            self.entity,
+            id,
+            points,
+            color,
+            duration,
+            resets_cooldown,
+        ))
+    }
+
+    /// Try to apply a percentage-based limit
+    pub fn try_limit_percentage(
+        &mut self,
+        id: u32,
+        percentage: f32,
+        color: Color,
+        duration: Option<f32>,
+        resets_cooldown: bool,
+    ) -> Result<(), PowerError> {
+        self.try_apply_limit(ApplyLimitEvent::percentage(
+            self.entity,
+            id,
+            percentage,
+            color,
+            duration,
+            resets_cooldown,
+        ))
+    }
+
+    /// Spend power (always sends event, may fail)
+    pub fn spend(&mut self, amount: f32) -> Result<(), PowerError> {
+        self.power_bar()?;
+        self.system
+            .spend_events
+            .write(SpendPowerEvent::new(self.entity, amount));
+        Ok(())
+    }
+
+    /// Change power (add or subtract), never knocking the entity out
+    pub fn change(&mut self, amount: f32) -> Result<(), PowerError> {
+        self.power_bar()?;
+        self.system
+            .change_events
+            .write(PowerChangeEvent::safe(self.entity, amount));
+        Ok(())
+    }
+
+    /// Drain power down to zero, knocking the entity out if it runs out
+    pub fn drain(&mut self, amount: f32) -> Result<(), PowerError> {
+        self.power_bar()?;
+        self.system
+            .change_events
+            .write(PowerChangeEvent::drain(self.entity, amount));
+        Ok(())
+    }
+
+    /// Apply a limit (always sends event, may cause knockout)
+    ///
+    /// The event's entity is replaced by this API's entity.
+    pub fn apply_limit(&mut self, mut event: ApplyLimitEvent<R>) -> Result<(), PowerError> {
+        self.power_bar()?;
+        event.entity = self.entity;
+        self.system.limit_events.write(event);
+        Ok(())
+    }
+
+    /// Apply a points-based limit
+    pub fn limit_points(
+        &mut self,
+        id: u32,
+        points: f32,
+        color: Color,
+        duration: Option<f32>,
+        resets_cooldown: bool,
+    ) -> Result<(), PowerError> {
+        self.apply_limit(ApplyLimitEvent::points(
+            self.entity,
+            id,
+            points,
+            color,
+            duration,
+            resets_cooldown,
+        ))
+    }
+
+    /// Apply a percentage-based limit
+    pub fn limit_percentage(
+        &mut self,
+        id: u32,
+        percentage: f32,
+        color: Color,
+        duration: Option<f32>,
+        resets_cooldown: bool,
+    ) -> Result<(), PowerError> {
+        self.apply_limit(ApplyLimitEvent::percentage(
+            self.entity,
+            id,
+            percentage,
+            color,
+            duration,
+            resets_cooldown,
+        ))
+    }
+
+    /// Lift every stack of a limit
+    pub fn lift(&mut self, limit_id: u32) -> Result<(), PowerError> {
+        self.power_bar()?;
+        self.system
+            .lift_events
+            .write(LiftLimitEvent::all(self.entity, limit_id));
+        Ok(())
+    }
+
+    /// Lift only the oldest stack of a limit
+    pub fn lift_one(&mut self, limit_id: u32) -> Result<(), PowerError> {
+        self.power_bar()?;
+        self.system
+            .lift_events
+            .write(LiftLimitEvent::one(self.entity, limit_id));
+        Ok(())
+    }
+
+    /// Start a safe drain of `rate_per_sec` that pauses regeneration
+    pub fn start_drain(&mut self, id: u32, rate_per_sec: f32) -> Result<(), PowerError> {
+        self.start_drain_with(PowerDrain::new(id, rate_per_sec))
+    }
+
+    /// Start a configured drain, replacing any drain with the same ID
+    pub fn start_drain_with(&mut self, drain: PowerDrain) -> Result<(), PowerError> {
+        self.power_bar()?;
+        self.system
+            .start_drain_events
+            .write(StartDrainEvent::new(self.entity, drain));
+        Ok(())
+    }
+
+    /// Stop a drain
+    pub fn stop_drain(&mut self, id: u32) -> Result<(), PowerError> {
+        self.power_bar()?;
+        self.system
+            .stop_drain_events
+            .write(StopDrainEvent::new(self.entity, id));
+        Ok(())
+    }
+
+    /// Add a regeneration modifier, replacing any modifier with the same ID
+    pub fn modify_regen(
+        &mut self,
+        id: u32,
+        kind: RegenModifierKind,
+        duration: Option<f32>,
+    ) -> Result<(), PowerError> {
+        self.power_bar()?;
+        self.system
+            .regen_modifier_events
+            .write(ApplyRegenModifierEvent::new(
+                self.entity,
+                RegenModifier::new(id, kind, duration),
+            ));
+        Ok(())
+    }
+
+    /// Block regeneration until the modifier is removed or expires
+    pub fn block_regen(&mut self, id: u32, duration: Option<f32>) -> Result<(), PowerError> {
+        self.modify_regen(id, RegenModifierKind::Block, duration)
+    }
+
+    /// Remove a regeneration modifier
+    pub fn remove_regen_modifier(&mut self, id: u32) -> Result<(), PowerError> {
+        self.power_bar()?;
+        self.system
+            .remove_regen_modifier_events
+            .write(RemoveRegenModifierEvent::new(self.entity, id));
+        Ok(())
+    }
+
+    /// Revive a knocked out entity
+    pub fn revive(&mut self, power_amount: f32) -> Result<(), PowerError> {
+        self.power_bar()?;
+        self.system
+            .revive_events
+            .write(ReviveEvent::new(self.entity, power_amount));
+        Ok(())
+    }
+
+    /// Start reviving a knocked out `target`, channelled for `duration` seconds
+    ///
+    /// The `cost` is reserved from this entity's power when the attempt starts
+    /// and spent when it completes.
+    pub fn start_revive(
+        &mut self,
+        target: Entity,
+        duration: f32,
+        power_amount: f32,
+        cost: f32,
+    ) -> Result<(), PowerError> {
+        self.power_bar()?;
+        self.system.start_revive_events.write(StartReviveEvent::new(
+            self.entity,
+            target,
+            duration,
+            power_amount,
+            cost,
+        ));
+        Ok(())
+    }
+
+    /// Grant experience
+    pub fn gain_experience(&mut self, amount: f32) -> Result<(), PowerError> {
+        self.power_bar()?;
+        self.system
+            .experience_events
+            .write(GainExperienceEvent::new(self.entity, amount));
+        Ok(())
+    }
+}
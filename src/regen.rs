@@ -0,0 +1,249 @@
+use crate::resource::{Power, PowerResource};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// How the regeneration rate develops once the regeneration delay has passed
+///
+/// Every curve starts at `PowerRegeneration::base_rate` unless noted otherwise.
+#[derive(Reflect, Debug, Clone, PartialEq, Default)]
+#[reflect(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RegenCurve {
+    /// Always regenerate at `base_rate`
+    Constant,
+    /// Ramp linearly from `base_rate` to `max_rate`, gaining `ramp_speed` per second
+    #[default]
+    Linear,
+    /// Ease in exponentially from `base_rate` to `max_rate` over `duration` seconds
+    EaseIn { duration: f32 },
+    /// Regenerate a percentage of max power per second, ignoring the rates
+    PercentOfMax(f32),
+    /// Rates sampled every `step` seconds, interpolated linearly, the last sample holds
+    Sampled { step: f32, rates: Vec<f32> },
+}
+
+impl RegenCurve {
+    /// Rate of a `Sampled` curve at `time`, zero without samples
+    pub(crate) fn sample(step: f32, rates: &[f32], time: f32) -> f32 {
+        let Some(&last) = rates.last() else {
+            return 0.0;
+        };
+        if step <= 0.0 {
+            return last;
+        }
+        let position = time / step;
+        let index = position.floor() as usize;
+        match (rates.get(index), rates.get(index + 1)) {
+            (Some(&from), Some(&to)) => from + (to - from) * position.fract(),
+            _ => last,
+        }
+    }
+
+    /// Exponential ease-in from 0 to 1 over `duration` seconds
+    pub(crate) fn ease_in(duration: f32, time: f32) -> f32 {
+        let progress = if duration > 0.0 {
+            (time / duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        if progress > 0.0 {
+            2f32.powf(10.0 * progress - 10.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Highest power passive regeneration refills to
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RegenCeiling {
+    /// Fixed amount of power points
+    Points(f32),
+    /// Percentage of max power
+    Percentage(f32),
+}
+
+impl RegenCeiling {
+    /// Power regeneration stops at for the given max, never above max
+    pub fn value_for(&self, max: f32) -> f32 {
+        match *self {
+            RegenCeiling::Points(points) => points.min(max),
+            RegenCeiling::Percentage(percent) => (max * percent / 100.0).min(max),
+        }
+    }
+}
+
+/// Effect of a regeneration modifier
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RegenModifierKind {
+    /// Add power per second to the rate (negative to slow regeneration)
+    Add(f32),
+    /// Multiply the rate, applied after every `Add`
+    Multiply(f32),
+    /// Stop regeneration entirely while active
+    Block,
+}
+
+/// A regeneration buff or debuff, e.g. "+50% regen for 10s" or "no regen in combat"
+#[derive(Reflect, Debug, Clone)]
+#[reflect(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "RegenModifierData", into = "RegenModifierData")
+)]
+pub struct RegenModifier {
+    /// Unique identifier for this modifier
+    pub id: u32,
+    /// What the modifier does to the rate
+    pub kind: RegenModifierKind,
+    /// Optional timer for auto-removal (None = permanent until removed)
+    pub duration: Option<Timer>,
+}
+
+impl RegenModifier {
+    /// Create a new regeneration modifier
+    pub fn new(id: u32, kind: RegenModifierKind, duration: Option<f32>) -> Self {
+        Self {
+            id,
+            kind,
+            duration: duration.map(|secs| Timer::from_seconds(secs, TimerMode::Once)),
+        }
+    }
+
+    /// Update timer and check if the modifier should be removed
+    pub fn update(&mut self, delta: f32) -> bool {
+        if let Some(ref mut timer) = self.duration {
+            timer.tick(std::time::Duration::from_secs_f32(delta));
+            timer.is_finished()
+        } else {
+            false
+        }
+    }
+
+    /// Seconds left before this modifier expires (None if permanent)
+    pub fn remaining_secs(&self) -> Option<f32> {
+        self.duration.as_ref().map(|timer| timer.remaining_secs())
+    }
+
+    /// Check if this modifier is permanent (no timer)
+    pub fn is_permanent(&self) -> bool {
+        self.duration.is_none()
+    }
+}
+
+/// Serialized form of a `RegenModifier`, storing the timer as plain seconds
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RegenModifierData {
+    id: u32,
+    kind: RegenModifierKind,
+    /// Total duration in seconds (None for permanent)
+    duration: Option<f32>,
+    /// Seconds already elapsed on the timer
+    #[serde(default)]
+    elapsed: f32,
+}
+
+#[cfg(feature = "serde")]
+impl From<RegenModifier> for RegenModifierData {
+    fn from(modifier: RegenModifier) -> Self {
+        Self {
+            id: modifier.id,
+            kind: modifier.kind,
+            duration: modifier
+                .duration
+                .as_ref()
+                .map(|t| t.duration().as_secs_f32()),
+            elapsed: modifier
+                .duration
+                .as_ref()
+                .map(|t| t.elapsed_secs())
+                .unwrap_or(0.0),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<RegenModifierData> for RegenModifier {
+    fn from(data: RegenModifierData) -> Self {
+        let mut modifier = RegenModifier::new(data.id, data.kind, data.duration);
+        if let Some(ref mut timer) = modifier.duration {
+            timer.set_elapsed(std::time::Duration::from_secs_f32(data.elapsed));
+        }
+        modifier
+    }
+}
+
+/// Component holding every regeneration modifier active on an entity
+#[derive(Component, Reflect, Debug, Clone, Default)]
+#[reflect(Component, Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegenModifiers<R: PowerResource = Power> {
+    pub modifiers: Vec<RegenModifier>,
+    /// Resource whose regeneration is modified
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> RegenModifiers<R> {
+    /// Add a modifier, returning the modifier it replaced if one had the same ID
+    pub fn add(&mut self, modifier: RegenModifier) -> Option<RegenModifier> {
+        match self.modifiers.iter_mut().find(|m| m.id == modifier.id) {
+            Some(active) => Some(std::mem::replace(active, modifier)),
+            None => {
+                self.modifiers.push(modifier);
+                None
+            }
+        }
+    }
+
+    /// Remove a modifier by ID
+    pub fn remove(&mut self, id: u32) -> Option<RegenModifier> {
+        let index = self.modifiers.iter().position(|m| m.id == id)?;
+        Some(self.modifiers.remove(index))
+    }
+
+    /// Check if any active modifier blocks regeneration
+    pub fn is_blocked(&self) -> bool {
+        self.modifiers
+            .iter()
+            .any(|m| m.kind == RegenModifierKind::Block)
+    }
+
+    /// Apply every modifier to a regeneration rate, never below zero
+    pub fn apply(&self, rate: f32) -> f32 {
+        if self.is_blocked() {
+            return 0.0;
+        }
+        let (added, multiplier) =
+            self.modifiers
+                .iter()
+                .fold((0.0, 1.0), |(added, multiplier), m| match m.kind {
+                    RegenModifierKind::Add(amount) => (added + amount, multiplier),
+                    RegenModifierKind::Multiply(factor) => (added, multiplier * factor),
+                    RegenModifierKind::Block => (added, multiplier),
+                });
+        ((rate + added) * multiplier).max(0.0)
+    }
+
+    /// Update all modifier timers and remove expired ones, returns the expired modifiers
+    pub fn update_timers(&mut self, delta: f32) -> Vec<RegenModifier> {
+        let mut expired = Vec::new();
+
+        for mut modifier in std::mem::take(&mut self.modifiers) {
+            if modifier.update(delta) {
+                expired.push(modifier);
+            } else {
+                self.modifiers.push(modifier);
+            }
+        }
+
+        expired
+    }
+}
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use std::fmt::Debug;
+
+/// Marker for a kind of power resource, e.g. mana, stamina or rage
+///
+/// Power components, events and systems are generic over a resource, so
+/// several independent resources can live on one entity. Add a
+/// `PowerSystemPlugin::<R>` for every resource.
+pub trait PowerResource: TypePath + Default + Clone + Copy + Debug + Send + Sync + 'static {
+    /// Which way the resource runs, see [`PowerDirection`]
+    const DIRECTION: PowerDirection = PowerDirection::Depleting;
+}
+
+/// Which end of the bar a resource is spent toward
+#[derive(Reflect, Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[reflect(Default, Debug, Clone, PartialEq, Hash)]
+pub enum PowerDirection {
+    /// Spending lowers the bar, regeneration refills it and an empty bar
+    /// knocks the entity out, e.g. mana or stamina
+    #[default]
+    Depleting,
+    /// Spending and drains raise the bar, regeneration decays it toward zero
+    /// and a full bar overloads, e.g. heat or rage
+    Accumulating,
+}
+
+/// The default power resource, used when no resource is named
+#[derive(Reflect, Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[reflect(Default, Debug, Clone, PartialEq, Hash)]
+pub struct Power;
+
+impl PowerResource for Power {}
@@ -0,0 +1,85 @@
+use crate::resource::{Power, PowerResource};
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Why a revive attempt ended without reviving its target
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Debug, Clone, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReviveCancelReason {
+    /// Cancelled by a `CancelReviveEvent`
+    Cancelled,
+    /// Another attempt on the same target took its place
+    Replaced,
+    /// The target is not knocked out (anymore)
+    TargetNotKnockedOut,
+    /// The reviver is knocked out
+    ReviverKnockedOut,
+    /// The reviver can't pay the revive cost
+    InsufficientPower,
+    /// The reviver or target no longer has a `PowerBar`
+    MissingComponent,
+    /// The `ReviveAttempt` was removed from outside, or its target despawned
+    Removed,
+}
+
+/// A channelled revive of a knocked out entity by another entity
+///
+/// Lives on the target while the attempt is in progress. The cost is reserved
+/// on the reviver when the attempt starts and spent when it completes.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReviveAttempt<R: PowerResource = Power> {
+    /// Entity performing the revive
+    pub reviver: Entity,
+    /// Knocked out entity being revived
+    pub target: Entity,
+    /// Seconds channelled so far
+    pub progress: f32,
+    /// Seconds needed to complete the revive
+    pub duration: f32,
+    /// Power the target is revived with
+    pub power_amount: f32,
+    /// Power the reviver pays on completion
+    pub cost: f32,
+    /// Resource the target is revived in
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> ReviveAttempt<R> {
+    /// Completed fraction of the attempt (0.0 to 1.0)
+    pub fn fraction(&self) -> f32 {
+        if self.duration > 0.0 {
+            (self.progress / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Check if the attempt has been channelled long enough
+    pub fn is_complete(&self) -> bool {
+        self.progress >= self.duration
+    }
+}
+
+/// Targets whose attempt the power systems ended before removing it
+///
+/// Any other removal of a `ReviveAttempt` is cancelled by an observer.
+#[derive(Resource)]
+pub(crate) struct SettledRevives<R: PowerResource = Power> {
+    pub(crate) targets: HashSet<Entity>,
+    resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> Default for SettledRevives<R> {
+    fn default() -> Self {
+        Self {
+            targets: HashSet::default(),
+            resource: PhantomData,
+        }
+    }
+}
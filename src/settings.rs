@@ -0,0 +1,55 @@
+use crate::{
+    components::PowerRegeneration,
+    knockout::KnockoutPolicy,
+    resource::{Power, PowerResource},
+};
+use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Clock that drives regeneration, drains and every power timer
+#[derive(Reflect, Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[reflect(Default, Debug, Clone, PartialEq, Hash)]
+pub enum PowerTimeSource {
+    /// The schedule's `Time`: virtual time in `Update`, fixed time in `FixedUpdate`
+    #[default]
+    Schedule,
+    /// `Time<Virtual>`, paused and scaled with the game
+    ///
+    /// Not allowed in fixed schedules, where every tick would see the whole frame;
+    /// the plugin panics on that combination.
+    Virtual,
+    /// `Time<Real>`, keeps running while the game is paused or slowed down
+    ///
+    /// Not allowed in fixed schedules, like `Virtual`.
+    Real,
+}
+
+/// Project-wide defaults of resource `R`, set up from the plugin config
+///
+/// Can be changed at runtime; entities with their own components keep them.
+#[derive(Resource, Reflect, Debug, Clone)]
+#[reflect(Resource, Debug, Clone)]
+pub struct PowerSettings<R: PowerResource = Power> {
+    /// Regeneration inserted on new power bars spawned without a `PowerRegeneration`
+    pub default_regen: PowerRegeneration<R>,
+    /// Knockout policy of entities without a `KnockoutPolicy` component
+    pub default_knockout_policy: KnockoutPolicy,
+    /// Clock the power timers follow
+    pub time_source: PowerTimeSource,
+}
+
+impl<R: PowerResource> Default for PowerSettings<R> {
+    fn default() -> Self {
+        Self {
+            default_regen: PowerRegeneration::default(),
+            default_knockout_policy: KnockoutPolicy::default(),
+            time_source: PowerTimeSource::default(),
+        }
+    }
+}
+
+/// Context of the `Time<PowerClock<R>>` resource the power systems of `R` read
+///
+/// Advanced once per run of the power schedule from the configured `PowerTimeSource`.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct PowerClock<R: PowerResource = Power>(PhantomData<R>);
@@ -0,0 +1,141 @@
+use crate::{
+    components::{PowerBar, PowerLevel, PowerRegeneration},
+    drains::PowerDrains,
+    error::PowerError,
+    grace::GraceWindow,
+    knockout::{KnockoutPolicy, KnockoutState},
+    limits::PowerLimits,
+    regen::RegenModifiers,
+    resource::{Power, PowerResource},
+    settings::PowerSettings,
+};
+use bevy::prelude::*;
+
+/// Current version of the `PowerSnapshot` format
+pub const POWER_SNAPSHOT_VERSION: u32 = 1;
+
+/// Saved power state of a single entity
+///
+/// Captures the power bar, level and experience, regeneration ramp state,
+/// knockout progress, regeneration modifiers, drains, the grace window with its
+/// deferred limits and every active limit, including the time left on timed
+/// ones. Timers are stored as plain seconds. With the `serde` feature enabled
+/// the snapshot can be written to and read from any serde format.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "")
+)]
+pub struct PowerSnapshot<R: PowerResource = Power> {
+    /// Format version, checked when applying
+    pub version: u32,
+    pub power_bar: PowerBar<R>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub power_level: Option<PowerLevel<R>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub power_regeneration: Option<PowerRegeneration<R>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub power_limits: Option<PowerLimits<R>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub knockout_state: Option<KnockoutState<R>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub regen_modifiers: Option<RegenModifiers<R>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub power_drains: Option<PowerDrains<R>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub grace_window: Option<GraceWindow<R>>,
+}
+
+impl<R: PowerResource> PowerSnapshot<R> {
+    /// Capture the state of resource `R` on an entity
+    pub fn capture(world: &World, entity: Entity) -> Result<Self, PowerError> {
+        let power_bar = world
+            .get::<PowerBar<R>>(entity)
+            .ok_or(PowerError::MissingPowerBar(entity))?;
+
+        Ok(Self {
+            version: POWER_SNAPSHOT_VERSION,
+            // Reservations are only held until their spend is processed
+            power_bar: PowerBar {
+                reserved: 0.0,
+                ..power_bar.clone()
+            },
+            power_level: world.get::<PowerLevel<R>>(entity).cloned(),
+            power_regeneration: world.get::<PowerRegeneration<R>>(entity).cloned(),
+            power_limits: world.get::<PowerLimits<R>>(entity).cloned(),
+            knockout_state: world.get::<KnockoutState<R>>(entity).cloned(),
+            regen_modifiers: world.get::<RegenModifiers<R>>(entity).cloned(),
+            power_drains: world.get::<PowerDrains<R>>(entity).cloned(),
+            grace_window: world.get::<GraceWindow<R>>(entity).cloned(),
+        })
+    }
+
+    /// Restore the captured power state onto an entity
+    ///
+    /// A missing level, regeneration or limits leaves the entity's own untouched,
+    /// while drains, regeneration modifiers and a grace window missing from the
+    /// snapshot are removed. The knockout state always follows the restored bar:
+    /// a knocked out bar saved without one starts its knockout policy afresh.
+    pub fn apply(&self, world: &mut World, entity: Entity) -> Result<(), PowerError> {
+        if self.version > POWER_SNAPSHOT_VERSION {
+            return Err(PowerError::UnsupportedSnapshotVersion(self.version));
+        }
+
+        let knockout_state = match (&self.knockout_state, self.power_bar.is_knocked_out) {
+            (_, false) => None,
+            (Some(state), true) => Some(state.clone()),
+            (None, true) => {
+                let policy = world
+                    .get::<KnockoutPolicy>(entity)
+                    .copied()
+                    .or_else(|| {
+                        world
+                            .get_resource::<PowerSettings<R>>()
+                            .map(|settings| settings.default_knockout_policy)
+                    })
+                    .unwrap_or_default();
+                Some(KnockoutState::for_policy(policy))
+            }
+        };
+
+        let mut entity_mut = world
+            .get_entity_mut(entity)
+            .map_err(|_| PowerError::MissingPowerBar(entity))?;
+
+        entity_mut.insert(self.power_bar.clone());
+        if let Some(power_level) = &self.power_level {
+            entity_mut.insert(power_level.clone());
+        }
+        if let Some(power_regeneration) = &self.power_regeneration {
+            entity_mut.insert(power_regeneration.clone());
+        }
+        if let Some(power_limits) = &self.power_limits {
+            entity_mut.insert(power_limits.clone());
+        }
+        match &self.regen_modifiers {
+            Some(regen_modifiers) => entity_mut.insert(regen_modifiers.clone()),
+            None => entity_mut.remove::<RegenModifiers<R>>(),
+        };
+        match &self.power_drains {
+            Some(power_drains) => entity_mut.insert(power_drains.clone()),
+            None => entity_mut.remove::<PowerDrains<R>>(),
+        };
+        match &self.grace_window {
+            Some(grace_window) => {
+                // Deferred limits were captured on another entity, or loaded without one
+                let mut grace_window = grace_window.clone();
+                for limit in &mut grace_window.deferred_limits {
+                    limit.entity = entity;
+                }
+                entity_mut.insert(grace_window)
+            }
+            None => entity_mut.remove::<GraceWindow<R>>(),
+        };
+        match knockout_state {
+            Some(state) => entity_mut.insert(state),
+            None => entity_mut.remove::<KnockoutState<R>>(),
+        };
+        Ok(())
+    }
+}
@@ -1,28 +1,159 @@
 use crate::{
     components::{PowerBar, PowerLevel, PowerRegeneration},
+    drains::{DrainEndReason, PowerDrains},
+    error::FailureReason,
     events::{
-        ApplyLimitEvent, KnockedOutEvent, LevelUpEvent, LiftLimitEvent, PowerChangeEvent,
-        ReviveEvent, SpendPowerEvent,
+        ApplyLimitEvent, ApplyRegenModifierEvent, CancelReviveEvent, ChangeMode, DownedEvent,
+        DrainEndedEvent, GainExperienceEvent, GraceEndedEvent, GraceStartedEvent, KnockedOutEvent,
+        LevelUpEvent, LiftLimitEvent, LimitAppliedEvent, LimitExpiredEvent, LimitLiftedEvent,
+        LimitRefreshedEvent, LimitRejectedEvent, OverloadClearedEvent, OverloadedEvent,
+        PowerChangeCause, PowerChangeEvent, PowerChangedEvent, RegenModifierExpiredEvent,
+        RemoveRegenModifierEvent, ReviveCancelledEvent, ReviveEvent, ReviveStartedEvent,
+        SpendFailedEvent, SpendPowerEvent, StartDrainEvent, StartReviveEvent, StopDrainEvent,
     },
-    limits::{PowerLimit, PowerLimits},
+    grace::{GracePolicy, GraceWindow},
+    knockout::{KnockoutPolicy, KnockoutStage, KnockoutState},
+    leveling::LevelingCurves,
+    limits::{LimitStackOutcome, PowerLimit, PowerLimits},
+    regen::RegenModifiers,
+    resource::{PowerDirection, PowerResource},
+    revive::{ReviveAttempt, ReviveCancelReason, SettledRevives},
+    settings::{PowerClock, PowerSettings, PowerTimeSource},
 };
+use bevy::platform::collections::HashMap;
 use bevy::prelude::*;
+use std::marker::PhantomData;
+
+/// Write a `PowerChangedEvent` if current or max power differ from `before`
+fn write_power_changed<R: PowerResource>(
+    writer: &mut MessageWriter<PowerChangedEvent<R>>,
+    entity: Entity,
+    before: (f32, f32),
+    power_bar: &PowerBar<R>,
+    cause: PowerChangeCause,
+) {
+    let (old, old_max) = before;
+    if old != power_bar.current || old_max != power_bar.max {
+        writer.write(PowerChangedEvent {
+            entity,
+            old,
+            new: power_bar.current,
+            max: power_bar.max,
+            cause,
+            resource: PhantomData,
+        });
+    }
+}
+
+/// Revive a knocked out entity whose policy waits for a limit to lift
+fn revive_on_limit_lift<R: PowerResource>(
+    writer: &mut MessageWriter<ReviveEvent<R>>,
+    entity: Entity,
+    power_bar: &PowerBar<R>,
+    policy: &KnockoutPolicy,
+) {
+    if let KnockoutPolicy::ReviveOnLimitLift { power } = *policy {
+        if power_bar.is_knocked_out && power_bar.max > 0.0 {
+            writer.write(ReviveEvent {
+                entity,
+                power_amount: power,
+                resource: PhantomData,
+            });
+        }
+    }
+}
+
+/// System to advance the power clock from the configured time source
+pub fn tick_power_clock<R: PowerResource>(
+    settings: Res<PowerSettings<R>>,
+    time: Res<Time>,
+    virtual_time: Res<Time<Virtual>>,
+    real_time: Res<Time<Real>>,
+    mut clock: ResMut<Time<PowerClock<R>>>,
+) {
+    let delta = match settings.time_source {
+        PowerTimeSource::Schedule => time.delta(),
+        PowerTimeSource::Virtual => virtual_time.delta(),
+        PowerTimeSource::Real => real_time.delta(),
+    };
+    clock.advance_by(delta);
+}
+
+/// Observer giving new power bars without a regeneration the default one
+///
+/// Inserts the `default_regen` of the `PowerSettings`, e.g. for `PowerBundle::new`.
+pub fn insert_default_regen<R: PowerResource>(
+    add: On<Add, PowerBar<R>>,
+    mut commands: Commands,
+    settings: Res<PowerSettings<R>>,
+) {
+    commands
+        .entity(add.entity)
+        .insert_if_new(settings.default_regen.clone());
+}
 
 /// System to handle power spending events
-pub fn handle_spend_power(
-    mut events: MessageReader<SpendPowerEvent>,
-    mut query: Query<(&mut PowerBar, &mut PowerRegeneration, Option<&PowerLimits>)>,
+#[allow(clippy::type_complexity)]
+pub fn handle_spend_power<R: PowerResource>(
+    mut events: MessageReader<SpendPowerEvent<R>>,
+    mut query: Query<(
+        &mut PowerBar<R>,
+        Option<&mut PowerRegeneration<R>>,
+        Option<&PowerLimits<R>>,
+        Option<&GraceWindow<R>>,
+    )>,
+    mut changed_events: MessageWriter<PowerChangedEvent<R>>,
+    mut failed_events: MessageWriter<SpendFailedEvent<R>>,
 ) {
     for event in events.read() {
-        if let Ok((mut power_bar, mut regen, limits)) = query.get_mut(event.entity) {
-            if power_bar.spend(event.amount) {
+        let Ok((mut power_bar, regen, limits, grace)) = query.get_mut(event.entity) else {
+            failed_events.write(SpendFailedEvent {
+                entity: event.entity,
+                amount: event.amount,
+                reason: FailureReason::MissingComponent,
+                resource: PhantomData,
+            });
+            continue;
+        };
+
+        // The reserved amount is now paid for by this spend, or given back if it fails
+        if event.reserved {
+            power_bar.release(event.amount);
+        }
+
+        let before = (power_bar.current, power_bar.max);
+        let result = match grace {
+            Some(grace) if grace.blocks_spends => Err(FailureReason::InGrace),
+            _ => power_bar.check_spend(event.amount),
+        };
+        match result {
+            Err(reason) => {
+                failed_events.write(SpendFailedEvent {
+                    entity: event.entity,
+                    amount: event.amount,
+                    reason,
+                    resource: PhantomData,
+                });
+            }
+            Ok(()) => {
+                power_bar.spend(event.amount);
+                write_power_changed(
+                    &mut changed_events,
+                    event.entity,
+                    before,
+                    &power_bar,
+                    PowerChangeCause::Spend,
+                );
+
                 // Reset regeneration on successful spend
-                regen.reset();
+                if let Some(mut regen) = regen {
+                    regen.reset();
 
-                // Check if limits should reset cooldown
-                if let Some(limits) = limits {
-                    if limits.any_resets_cooldown() {
-                        regen.reset();
+                    // Check if limits should reset cooldown
+                    if let Some(limits) = limits {
+                        if limits.any_resets_cooldown() {
+                            regen.reset();
+                        }
                     }
                 }
             }
@@ -31,169 +162,1088 @@ pub fn handle_spend_power(
 }
 
 /// System to handle power change events (add/subtract)
-pub fn handle_power_change(
-    mut events: MessageReader<PowerChangeEvent>,
-    mut query: Query<&mut PowerBar>,
+pub fn handle_power_change<R: PowerResource>(
+    mut events: MessageReader<PowerChangeEvent<R>>,
+    mut query: Query<(&mut PowerBar<R>, Option<&GraceWindow<R>>)>,
+    mut changed_events: MessageWriter<PowerChangedEvent<R>>,
 ) {
     for event in events.read() {
-        if let Ok(mut power_bar) = query.get_mut(event.entity) {
-            if event.amount > 0.0 {
-                power_bar.add(event.amount);
-            } else {
-                power_bar.spend(event.amount.abs());
+        if let Ok((mut power_bar, grace)) = query.get_mut(event.entity) {
+            // Grace windows block or reduce decreases
+            let amount = grace.map_or(event.amount, |grace| grace.scale_change(event.amount));
+            let before = (power_bar.current, power_bar.max);
+            if amount > 0.0 {
+                power_bar.add(amount);
+            } else if amount < 0.0 {
+                match event.mode {
+                    ChangeMode::Safe => {
+                        power_bar.spend(amount.abs());
+                    }
+                    ChangeMode::Drain => power_bar.drain(amount.abs()),
+                }
             }
+            write_power_changed(
+                &mut changed_events,
+                event.entity,
+                before,
+                &power_bar,
+                PowerChangeCause::Change,
+            );
         }
     }
 }
 
 /// System to handle power regeneration
-pub fn regenerate_power(
-    time: Res<Time>,
-    mut query: Query<(&mut PowerBar, &mut PowerRegeneration)>,
+#[allow(clippy::type_complexity)]
+pub fn regenerate_power<R: PowerResource>(
+    time: Res<Time<PowerClock<R>>>,
+    mut query: Query<(
+        Entity,
+        &mut PowerBar<R>,
+        &mut PowerRegeneration<R>,
+        Option<&RegenModifiers<R>>,
+    )>,
+    mut changed_events: MessageWriter<PowerChangedEvent<R>>,
 ) {
     let delta = time.delta_secs();
 
-    for (mut power_bar, mut regen) in query.iter_mut() {
-        if !power_bar.is_knocked_out {
-            regen.update(delta);
-            let regen_amount = regen.get_regen_amount(delta);
-            if regen_amount > 0.0 {
-                power_bar.add(regen_amount);
+    for (entity, mut power_bar, mut regen, modifiers) in query.iter_mut() {
+        // A full accumulating bar overloads before it starts cooling down
+        let overloading = R::DIRECTION == PowerDirection::Accumulating
+            && !power_bar.is_overloaded
+            && power_bar.remaining() <= 0.0;
+        if !power_bar.is_knocked_out && !overloading {
+            regen.update(delta, power_bar.max);
+            let regen_amount = match modifiers {
+                Some(modifiers) if regen.is_active => modifiers.apply(regen.current_rate) * delta,
+                _ => regen.get_regen_amount(delta),
+            };
+            // On accumulating resources this decays toward zero and the ceiling caps the
+            // headroom, an overloaded bar always cools down fully
+            let ceiling = if power_bar.is_overloaded {
+                power_bar.max
+            } else {
+                regen.ceiling_for(power_bar.max)
+            };
+            if regen_amount > 0.0 && power_bar.remaining() < ceiling {
+                let before = (power_bar.current, power_bar.max);
+                let room = ceiling - power_bar.remaining();
+                power_bar.add(regen_amount.min(room));
+                write_power_changed(
+                    &mut changed_events,
+                    entity,
+                    before,
+                    &power_bar,
+                    PowerChangeCause::Regen,
+                );
             }
         }
     }
 }
 
-/// System to handle applying power limits
-pub fn handle_apply_limit(
-    mut events: MessageReader<ApplyLimitEvent>,
-    mut query: Query<(&mut PowerBar, &mut PowerRegeneration, &mut PowerLimits)>,
-    mut knocked_out_events: MessageWriter<KnockedOutEvent>,
+/// System to handle adding regeneration modifiers
+pub fn handle_apply_regen_modifier<R: PowerResource>(
+    mut commands: Commands,
+    mut events: MessageReader<ApplyRegenModifierEvent<R>>,
+    mut query: Query<Option<&mut RegenModifiers<R>>, With<PowerRegeneration<R>>>,
 ) {
+    // Modifiers for entities without a `RegenModifiers` component yet, inserted once
+    let mut pending: HashMap<Entity, RegenModifiers<R>> = HashMap::default();
+
     for event in events.read() {
-        if let Ok((mut power_bar, mut regen, mut limits)) = query.get_mut(event.entity) {
-            let new_limit = PowerLimit::new(
-                event.id,
-                event.limit_type,
-                event.color,
-                event.duration,
-                event.resets_cooldown,
-            );
+        let Ok(modifiers) = query.get_mut(event.entity) else {
+            continue;
+        };
+        match modifiers {
+            Some(mut modifiers) => modifiers.add(event.modifier.clone()),
+            None => pending
+                .entry(event.entity)
+                .or_default()
+                .add(event.modifier.clone()),
+        };
+    }
 
-            limits.add_limit(new_limit, power_bar.base_max);
+    for (entity, modifiers) in pending {
+        commands.entity(entity).insert(modifiers);
+    }
+}
 
-            // Update max power based on limits
-            let total_reduction = limits.total_reduction();
-            power_bar.max = (power_bar.base_max - total_reduction).max(0.0);
+/// System to handle removing regeneration modifiers
+pub fn handle_remove_regen_modifier<R: PowerResource>(
+    mut events: MessageReader<RemoveRegenModifierEvent<R>>,
+    mut query: Query<&mut RegenModifiers<R>>,
+) {
+    for event in events.read() {
+        if let Ok(mut modifiers) = query.get_mut(event.entity) {
+            modifiers.remove(event.id);
+        }
+    }
+}
 
-            // Clamp current power to new max
-            if power_bar.current > power_bar.max {
-                power_bar.current = power_bar.max;
-            }
+/// System to update regeneration modifier timers and remove expired ones
+pub fn update_regen_modifier_timers<R: PowerResource>(
+    time: Res<Time<PowerClock<R>>>,
+    mut query: Query<(Entity, &mut RegenModifiers<R>)>,
+    mut expired_events: MessageWriter<RegenModifierExpiredEvent<R>>,
+) {
+    let delta = time.delta_secs();
 
-            // Check for knockout
-            if power_bar.max <= 0.0 || power_bar.current <= 0.0 {
-                power_bar.is_knocked_out = true;
-                knocked_out_events.write(KnockedOutEvent {
+    for (entity, mut modifiers) in query.iter_mut() {
+        for modifier in modifiers.update_timers(delta) {
+            expired_events.write(RegenModifierExpiredEvent {
+                entity,
+                id: modifier.id,
+                resource: PhantomData,
+            });
+        }
+    }
+}
+
+/// System to handle starting continuous drains
+pub fn handle_start_drain<R: PowerResource>(
+    mut commands: Commands,
+    mut events: MessageReader<StartDrainEvent<R>>,
+    mut query: Query<Option<&mut PowerDrains<R>>, With<PowerBar<R>>>,
+    mut ended_events: MessageWriter<DrainEndedEvent<R>>,
+) {
+    // Drains for entities without a `PowerDrains` component yet, inserted once
+    let mut pending: HashMap<Entity, PowerDrains<R>> = HashMap::default();
+
+    for event in events.read() {
+        let Ok(drains) = query.get_mut(event.entity) else {
+            continue;
+        };
+        let replaced = match drains {
+            Some(mut drains) => drains.start(event.drain.clone()),
+            None => pending
+                .entry(event.entity)
+                .or_default()
+                .start(event.drain.clone()),
+        };
+        if let Some(replaced) = replaced {
+            ended_events.write(DrainEndedEvent {
+                entity: event.entity,
+                id: replaced.id,
+                reason: DrainEndReason::Replaced,
+                resource: PhantomData,
+            });
+        }
+    }
+
+    for (entity, drains) in pending {
+        commands.entity(entity).insert(drains);
+    }
+}
+
+/// System to handle stopping continuous drains
+pub fn handle_stop_drain<R: PowerResource>(
+    mut events: MessageReader<StopDrainEvent<R>>,
+    mut query: Query<&mut PowerDrains<R>>,
+    mut ended_events: MessageWriter<DrainEndedEvent<R>>,
+) {
+    for event in events.read() {
+        if let Ok(mut drains) = query.get_mut(event.entity) {
+            if let Some(drain) = drains.stop(event.id) {
+                ended_events.write(DrainEndedEvent {
                     entity: event.entity,
+                    id: drain.id,
+                    reason: DrainEndReason::Stopped,
+                    resource: PhantomData,
+                });
+            }
+        }
+    }
+}
+
+/// System to tick continuous drains
+///
+/// Safe drains end when they can no longer be paid without a knockout, every
+/// drain ends when power runs out or the entity is knocked out. On accumulating
+/// resources drains build the bar up and end when it overloads. Grace windows
+/// scale drains like other decreases and pause safe drains that would be spends.
+#[allow(clippy::type_complexity)]
+pub fn tick_power_drains<R: PowerResource>(
+    time: Res<Time<PowerClock<R>>>,
+    mut query: Query<(
+        Entity,
+        &mut PowerBar<R>,
+        &mut PowerDrains<R>,
+        Option<&mut PowerRegeneration<R>>,
+        Option<&GraceWindow<R>>,
+    )>,
+    mut changed_events: MessageWriter<PowerChangedEvent<R>>,
+    mut ended_events: MessageWriter<DrainEndedEvent<R>>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut power_bar, mut drains, regen, grace) in query.iter_mut() {
+        if drains.drains.is_empty() {
+            continue;
+        }
+
+        if power_bar.is_knocked_out || power_bar.is_overloaded {
+            let reason = if power_bar.is_knocked_out {
+                DrainEndReason::KnockedOut
+            } else {
+                DrainEndReason::Overloaded
+            };
+            for drain in drains.drains.drain(..) {
+                ended_events.write(DrainEndedEvent {
+                    entity,
+                    id: drain.id,
+                    reason,
+                    resource: PhantomData,
                 });
             }
+            continue;
+        }
 
-            // Reset cooldown if needed (stops regeneration for 2.5 seconds)
-            if event.resets_cooldown {
+        // Pause regeneration for the frame like a spend does
+        if drains.pauses_regen() {
+            if let Some(mut regen) = regen {
                 regen.reset();
             }
         }
+
+        let before = (power_bar.current, power_bar.max);
+        let mut exhausted = Vec::new();
+        for drain in &drains.drains {
+            // Grace windows reduce drains like any other decrease and hold safe
+            // drains while they block spends
+            let amount = drain.rate_per_sec * delta;
+            let amount = grace.map_or(amount, |grace| -grace.scale_change(-amount));
+            if amount <= 0.0 {
+                continue;
+            }
+            match drain.mode {
+                ChangeMode::Safe if grace.is_some_and(|grace| grace.blocks_spends) => {}
+                ChangeMode::Safe => {
+                    if !power_bar.spend(amount) {
+                        exhausted.push(drain.id);
+                    }
+                }
+                ChangeMode::Drain => power_bar.drain(amount),
+            }
+        }
+        write_power_changed(
+            &mut changed_events,
+            entity,
+            before,
+            &power_bar,
+            PowerChangeCause::Drain,
+        );
+
+        let out_of_power = power_bar.remaining() <= 0.0;
+        drains.drains.retain(|drain| {
+            let ended = out_of_power || exhausted.contains(&drain.id);
+            if ended {
+                ended_events.write(DrainEndedEvent {
+                    entity,
+                    id: drain.id,
+                    reason: DrainEndReason::Exhausted,
+                    resource: PhantomData,
+                });
+            }
+            !ended
+        });
+    }
+}
+
+/// System to handle applying power limits
+pub fn handle_apply_limit<R: PowerResource>(
+    mut events: MessageReader<ApplyLimitEvent<R>>,
+    mut query: Query<(
+        &mut PowerBar<R>,
+        &mut PowerRegeneration<R>,
+        &mut PowerLimits<R>,
+    )>,
+    mut applied_events: MessageWriter<LimitAppliedEvent<R>>,
+    mut refreshed_events: MessageWriter<LimitRefreshedEvent<R>>,
+    mut changed_events: MessageWriter<PowerChangedEvent<R>>,
+    mut rejected_events: MessageWriter<LimitRejectedEvent<R>>,
+    mut grace_query: Query<&mut GraceWindow<R>>,
+) {
+    for event in events.read() {
+        let Ok((mut power_bar, mut regen, mut limits)) = query.get_mut(event.entity) else {
+            rejected_events.write(LimitRejectedEvent {
+                entity: event.entity,
+                id: event.id,
+                limit_type: event.limit_type,
+                reason: FailureReason::MissingComponent,
+                resource: PhantomData,
+            });
+            continue;
+        };
+
+        // Limits wait for a grace window to end
+        if let Ok(mut grace) = grace_query.get_mut(event.entity) {
+            grace.deferred_limits.push(event.clone());
+            continue;
+        }
+
+        let new_limit = PowerLimit::new(
+            event.id,
+            event.limit_type,
+            event.color,
+            event.duration,
+            event.resets_cooldown,
+        )
+        .with_stacking(event.stacking);
+
+        match limits.add_limit(new_limit, power_bar.base_max) {
+            LimitStackOutcome::Ignored => continue,
+            LimitStackOutcome::Refreshed | LimitStackOutcome::Extended => {
+                refreshed_events.write(LimitRefreshedEvent {
+                    entity: event.entity,
+                    id: event.id,
+                    limit_type: event.limit_type,
+                    resource: PhantomData,
+                });
+            }
+            LimitStackOutcome::Added | LimitStackOutcome::Replaced | LimitStackOutcome::Stacked => {
+                applied_events.write(LimitAppliedEvent {
+                    entity: event.entity,
+                    id: event.id,
+                    limit_type: event.limit_type,
+                    resource: PhantomData,
+                });
+            }
+        }
+
+        // Update max power based on limits, clamping current power to it
+        let before = (power_bar.current, power_bar.max);
+        power_bar.recalculate_max(Some(&mut limits));
+        write_power_changed(
+            &mut changed_events,
+            event.entity,
+            before,
+            &power_bar,
+            PowerChangeCause::LimitClamp,
+        );
+
+        // A knockout is picked up by `detect_knockout` later this frame
+
+        // Reset cooldown if needed (stops regeneration for 2.5 seconds)
+        if event.resets_cooldown {
+            regen.reset();
+        }
     }
 }
 
 /// System to handle lifting power limits
-pub fn handle_lift_limit(
-    mut events: MessageReader<LiftLimitEvent>,
-    mut query: Query<(&mut PowerBar, &mut PowerLimits)>,
+pub fn handle_lift_limit<R: PowerResource>(
+    settings: Res<PowerSettings<R>>,
+    mut events: MessageReader<LiftLimitEvent<R>>,
+    mut query: Query<(
+        &mut PowerBar<R>,
+        &mut PowerLimits<R>,
+        Option<&KnockoutPolicy>,
+    )>,
+    mut lifted_events: MessageWriter<LimitLiftedEvent<R>>,
+    mut changed_events: MessageWriter<PowerChangedEvent<R>>,
+    mut revive_events: MessageWriter<ReviveEvent<R>>,
 ) {
     for event in events.read() {
-        if let Ok((mut power_bar, mut limits)) = query.get_mut(event.entity) {
-            if limits.remove_limit(event.id) {
+        if let Ok((mut power_bar, mut limits, policy)) = query.get_mut(event.entity) {
+            let removed = if event.all_stacks {
+                limits.remove_all(event.id)
+            } else {
+                limits.remove_limit(event.id).into_iter().collect()
+            };
+
+            for limit in removed.iter() {
+                lifted_events.write(LimitLiftedEvent {
+                    entity: event.entity,
+                    id: limit.id,
+                    limit_type: limit.limit_type,
+                    resource: PhantomData,
+                });
+            }
+
+            if !removed.is_empty() {
                 // Recalculate max power
-                let total_reduction = limits.total_reduction();
-                power_bar.max = (power_bar.base_max - total_reduction).max(0.0);
+                let before = (power_bar.current, power_bar.max);
+                power_bar.recalculate_max(Some(&mut limits));
+                write_power_changed(
+                    &mut changed_events,
+                    event.entity,
+                    before,
+                    &power_bar,
+                    PowerChangeCause::LimitClamp,
+                );
 
-                // If knocked out but now has max power, allow revival
-                if power_bar.is_knocked_out && power_bar.max > 0.0 {
-                    power_bar.is_knocked_out = false;
-                    power_bar.current = power_bar.current.min(power_bar.max);
-                }
+                let policy = policy.unwrap_or(&settings.default_knockout_policy);
+                revive_on_limit_lift(&mut revive_events, event.entity, &power_bar, policy);
             }
         }
     }
 }
 
 /// System to update limit timers and remove expired ones
-pub fn update_limit_timers(
-    time: Res<Time>,
-    mut query: Query<(Entity, &mut PowerBar, &mut PowerLimits)>,
+#[allow(clippy::type_complexity)]
+pub fn update_limit_timers<R: PowerResource>(
+    time: Res<Time<PowerClock<R>>>,
+    settings: Res<PowerSettings<R>>,
+    mut query: Query<(
+        Entity,
+        &mut PowerBar<R>,
+        &mut PowerLimits<R>,
+        Option<&KnockoutPolicy>,
+    )>,
+    mut expired_events: MessageWriter<LimitExpiredEvent<R>>,
+    mut changed_events: MessageWriter<PowerChangedEvent<R>>,
+    mut revive_events: MessageWriter<ReviveEvent<R>>,
 ) {
     let delta = time.delta_secs();
 
-    for (_entity, mut power_bar, mut limits) in query.iter_mut() {
-        let removed_ids = limits.update_timers(delta);
+    for (entity, mut power_bar, mut limits, policy) in query.iter_mut() {
+        let expired = limits.update_timers(delta);
+
+        for limit in expired.iter() {
+            expired_events.write(LimitExpiredEvent {
+                entity,
+                id: limit.id,
+                limit_type: limit.limit_type,
+                resource: PhantomData,
+            });
+        }
 
         // Update max power if any limits were removed
-        if !removed_ids.is_empty() {
-            let total_reduction = limits.total_reduction();
-            power_bar.max = (power_bar.base_max - total_reduction).max(0.0);
+        if !expired.is_empty() {
+            let before = (power_bar.current, power_bar.max);
+            power_bar.recalculate_max(Some(&mut limits));
+            write_power_changed(
+                &mut changed_events,
+                entity,
+                before,
+                &power_bar,
+                PowerChangeCause::LimitClamp,
+            );
 
-            // If knocked out but now has max power, allow revival
-            if power_bar.is_knocked_out && power_bar.max > 0.0 {
-                power_bar.is_knocked_out = false;
-                power_bar.current = power_bar.current.min(power_bar.max);
-            }
+            let policy = policy.unwrap_or(&settings.default_knockout_policy);
+            revive_on_limit_lift(&mut revive_events, entity, &power_bar, policy);
         }
     }
 }
 
 /// System to handle revival events
-pub fn handle_revive(mut events: MessageReader<ReviveEvent>, mut query: Query<&mut PowerBar>) {
+#[allow(clippy::type_complexity)]
+pub fn handle_revive<R: PowerResource>(
+    mut commands: Commands,
+    mut events: MessageReader<ReviveEvent<R>>,
+    mut query: Query<(
+        &mut PowerBar<R>,
+        Option<&GracePolicy>,
+        Option<&mut GraceWindow<R>>,
+    )>,
+    mut changed_events: MessageWriter<PowerChangedEvent<R>>,
+    mut grace_events: MessageWriter<GraceStartedEvent<R>>,
+) {
     for event in events.read() {
-        if let Ok(mut power_bar) = query.get_mut(event.entity) {
+        if let Ok((mut power_bar, policy, grace)) = query.get_mut(event.entity) {
+            let was_knocked_out = power_bar.is_knocked_out;
+            let before = (power_bar.current, power_bar.max);
             power_bar.revive(event.power_amount);
+            write_power_changed(
+                &mut changed_events,
+                event.entity,
+                before,
+                &power_bar,
+                PowerChangeCause::Revive,
+            );
+
+            // Start the grace window, keeping limits deferred by an earlier one
+            if let (true, Some(policy)) = (was_knocked_out && !power_bar.is_knocked_out, policy) {
+                start_grace(
+                    &mut commands,
+                    &mut grace_events,
+                    event.entity,
+                    policy,
+                    grace,
+                );
+            }
         }
     }
 }
 
+/// Start or restart the grace window of an entity
+fn start_grace<R: PowerResource>(
+    commands: &mut Commands,
+    grace_events: &mut MessageWriter<GraceStartedEvent<R>>,
+    entity: Entity,
+    policy: &GracePolicy,
+    grace: Option<Mut<GraceWindow<R>>>,
+) {
+    match grace {
+        Some(mut grace) => grace.restart(policy),
+        None => {
+            commands
+                .entity(entity)
+                .insert(GraceWindow::<R>::new(policy));
+        }
+    }
+    grace_events.write(GraceStartedEvent {
+        entity,
+        duration: policy.duration,
+        resource: PhantomData,
+    });
+}
+
+/// System to update grace windows, applying deferred limits when they end
+pub fn update_grace_windows<R: PowerResource>(
+    time: Res<Time<PowerClock<R>>>,
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut GraceWindow<R>)>,
+    mut limit_events: MessageWriter<ApplyLimitEvent<R>>,
+    mut ended_events: MessageWriter<GraceEndedEvent<R>>,
+) {
+    let delta = std::time::Duration::from_secs_f32(time.delta_secs());
+
+    for (entity, mut grace) in query.iter_mut() {
+        if grace.timer.tick(delta).just_finished() {
+            limit_events.write_batch(std::mem::take(&mut grace.deferred_limits));
+            commands.entity(entity).remove::<GraceWindow<R>>();
+            ended_events.write(GraceEndedEvent {
+                entity,
+                resource: PhantomData,
+            });
+        }
+    }
+}
+
+/// Release a revive attempt's reserved cost and report it as cancelled
+fn cancel_revive_attempt<R: PowerResource>(
+    attempt: &ReviveAttempt<R>,
+    power_query: &mut Query<&mut PowerBar<R>>,
+    cancelled_events: &mut MessageWriter<ReviveCancelledEvent<R>>,
+    reason: ReviveCancelReason,
+) {
+    if attempt.cost > 0.0 {
+        if let Ok(mut power_bar) = power_query.get_mut(attempt.reviver) {
+            power_bar.release(attempt.cost);
+        }
+    }
+    cancelled_events.write(ReviveCancelledEvent {
+        reviver: attempt.reviver,
+        target: attempt.target,
+        reason,
+        resource: PhantomData,
+    });
+}
+
+/// System to handle starting channelled revives
+pub fn handle_start_revive<R: PowerResource>(
+    mut commands: Commands,
+    mut events: MessageReader<StartReviveEvent<R>>,
+    mut power_query: Query<&mut PowerBar<R>>,
+    attempts: Query<&ReviveAttempt<R>>,
+    mut started_events: MessageWriter<ReviveStartedEvent<R>>,
+    mut cancelled_events: MessageWriter<ReviveCancelledEvent<R>>,
+) {
+    // Attempts started this frame, inserted once
+    let mut pending: HashMap<Entity, ReviveAttempt<R>> = HashMap::default();
+
+    for event in events.read() {
+        let target_reason = match power_query.get(event.target) {
+            Ok(power_bar) if power_bar.is_knocked_out => None,
+            Ok(_) => Some(ReviveCancelReason::TargetNotKnockedOut),
+            Err(_) => Some(ReviveCancelReason::MissingComponent),
+        };
+        // Attempt on the same target this one would replace
+        let replaced = pending
+            .get(&event.target)
+            .or_else(|| attempts.get(event.target).ok())
+            .cloned();
+        // Cost of the replaced attempt already handed over to this one
+        let mut handed_over = 0.0;
+
+        // The reviver only needs a PowerBar when the revive costs power
+        let reviver_reason = match power_query.get_mut(event.reviver) {
+            Ok(power_bar) if power_bar.is_knocked_out => {
+                Some(ReviveCancelReason::ReviverKnockedOut)
+            }
+            Ok(_) if target_reason.is_some() || event.cost <= 0.0 => None,
+            Ok(mut power_bar) => {
+                // A reviver restarting its own attempt can pay from the old reservation
+                let old_cost = replaced
+                    .as_ref()
+                    .filter(|replaced| replaced.reviver == event.reviver)
+                    .map_or(0.0, |replaced| replaced.cost);
+                power_bar.release(old_cost);
+                match power_bar.reserve(event.cost) {
+                    Ok(()) => {
+                        handed_over = old_cost;
+                        None
+                    }
+                    Err(_) => {
+                        power_bar.reserved += old_cost;
+                        Some(ReviveCancelReason::InsufficientPower)
+                    }
+                }
+            }
+            Err(_) if event.cost > 0.0 => Some(ReviveCancelReason::MissingComponent),
+            Err(_) => None,
+        };
+        if let Some(reason) = target_reason.or(reviver_reason) {
+            cancelled_events.write(ReviveCancelledEvent {
+                reviver: event.reviver,
+                target: event.target,
+                reason,
+                resource: PhantomData,
+            });
+            continue;
+        }
+
+        let attempt = ReviveAttempt {
+            reviver: event.reviver,
+            target: event.target,
+            progress: 0.0,
+            duration: event.duration,
+            power_amount: event.power_amount,
+            cost: event.cost,
+            resource: PhantomData,
+        };
+        pending.insert(event.target, attempt);
+        if let Some(mut replaced) = replaced {
+            replaced.cost -= handed_over;
+            cancel_revive_attempt(
+                &replaced,
+                &mut power_query,
+                &mut cancelled_events,
+                ReviveCancelReason::Replaced,
+            );
+        }
+        started_events.write(ReviveStartedEvent {
+            reviver: event.reviver,
+            target: event.target,
+            resource: PhantomData,
+        });
+    }
+
+    for (target, attempt) in pending {
+        commands.entity(target).insert(attempt);
+    }
+}
+
+/// System to handle cancelling channelled revives
+pub fn handle_cancel_revive<R: PowerResource>(
+    mut commands: Commands,
+    mut events: MessageReader<CancelReviveEvent<R>>,
+    mut power_query: Query<&mut PowerBar<R>>,
+    attempts: Query<&ReviveAttempt<R>>,
+    mut settled: ResMut<SettledRevives<R>>,
+    mut cancelled_events: MessageWriter<ReviveCancelledEvent<R>>,
+) {
+    for event in events.read() {
+        if let Ok(attempt) = attempts.get(event.target) {
+            cancel_revive_attempt(
+                attempt,
+                &mut power_query,
+                &mut cancelled_events,
+                ReviveCancelReason::Cancelled,
+            );
+            settled.targets.insert(event.target);
+            commands.entity(event.target).remove::<ReviveAttempt<R>>();
+        }
+    }
+}
+
+/// System to channel revive attempts and complete them with a `ReviveEvent`
+///
+/// Attempts end early when the target recovers or the reviver is knocked out.
+/// On completion the reviver pays the reserved cost, or the attempt is cancelled
+/// if the reviver no longer has the power for it.
+#[allow(clippy::too_many_arguments)]
+pub fn update_revive_attempts<R: PowerResource>(
+    time: Res<Time<PowerClock<R>>>,
+    mut commands: Commands,
+    mut attempts: Query<(Entity, &mut ReviveAttempt<R>)>,
+    mut settled: ResMut<SettledRevives<R>>,
+    mut power_query: Query<&mut PowerBar<R>>,
+    mut regen_query: Query<&mut PowerRegeneration<R>>,
+    mut changed_events: MessageWriter<PowerChangedEvent<R>>,
+    mut revive_events: MessageWriter<ReviveEvent<R>>,
+    mut cancelled_events: MessageWriter<ReviveCancelledEvent<R>>,
+) {
+    let delta = time.delta_secs();
+
+    for (target, mut attempt) in attempts.iter_mut() {
+        let reason = match power_query.get(target) {
+            Ok(power_bar) if !power_bar.is_knocked_out => {
+                Some(ReviveCancelReason::TargetNotKnockedOut)
+            }
+            Ok(_) => match power_query.get(attempt.reviver) {
+                Ok(power_bar) if power_bar.is_knocked_out => {
+                    Some(ReviveCancelReason::ReviverKnockedOut)
+                }
+                Err(_) if attempt.cost > 0.0 => Some(ReviveCancelReason::MissingComponent),
+                _ => None,
+            },
+            Err(_) => Some(ReviveCancelReason::MissingComponent),
+        };
+        if let Some(reason) = reason {
+            cancel_revive_attempt(&attempt, &mut power_query, &mut cancelled_events, reason);
+            settled.targets.insert(target);
+            commands.entity(target).remove::<ReviveAttempt<R>>();
+            continue;
+        }
+
+        attempt.progress += delta;
+        if attempt.is_complete() {
+            // Pay straight from the reservation, a grace window started since
+            // must not make the revive free. Drains may have eaten into the
+            // reserved power, a reviver who can't pay anymore cancels instead.
+            if attempt.cost > 0.0 {
+                if let Ok(mut power_bar) = power_query.get_mut(attempt.reviver) {
+                    let before = (power_bar.current, power_bar.max);
+                    power_bar.release(attempt.cost);
+                    if !power_bar.spend(attempt.cost) {
+                        cancelled_events.write(ReviveCancelledEvent {
+                            reviver: attempt.reviver,
+                            target,
+                            reason: ReviveCancelReason::InsufficientPower,
+                            resource: PhantomData,
+                        });
+                        settled.targets.insert(target);
+                        commands.entity(target).remove::<ReviveAttempt<R>>();
+                        continue;
+                    }
+                    write_power_changed(
+                        &mut changed_events,
+                        attempt.reviver,
+                        before,
+                        &power_bar,
+                        PowerChangeCause::Spend,
+                    );
+                }
+                if let Ok(mut regen) = regen_query.get_mut(attempt.reviver) {
+                    regen.reset();
+                }
+            }
+            revive_events.write(ReviveEvent {
+                entity: target,
+                power_amount: attempt.power_amount,
+                resource: PhantomData,
+            });
+            settled.targets.insert(target);
+            commands.entity(target).remove::<ReviveAttempt<R>>();
+        }
+    }
+}
+
+/// Observer cancelling revive attempts removed outside the power systems
+///
+/// Releases the reviver's reserved cost, e.g. when the target is despawned in
+/// the middle of a revive.
+pub fn cancel_removed_revive<R: PowerResource>(
+    remove: On<Remove, ReviveAttempt<R>>,
+    attempts: Query<&ReviveAttempt<R>>,
+    mut settled: ResMut<SettledRevives<R>>,
+    mut power_query: Query<&mut PowerBar<R>>,
+    mut cancelled_events: MessageWriter<ReviveCancelledEvent<R>>,
+) {
+    if settled.targets.remove(&remove.entity) {
+        return;
+    }
+    if let Ok(attempt) = attempts.get(remove.entity) {
+        cancel_revive_attempt(
+            attempt,
+            &mut power_query,
+            &mut cancelled_events,
+            ReviveCancelReason::Removed,
+        );
+    }
+}
+
+/// System to handle experience grants
+pub fn handle_gain_experience<R: PowerResource>(
+    mut events: MessageReader<GainExperienceEvent<R>>,
+    mut query: Query<&mut PowerLevel<R>>,
+) {
+    for event in events.read() {
+        if let Ok(mut power_level) = query.get_mut(event.entity) {
+            power_level.add_experience(event.amount);
+        }
+    }
+}
+
+/// System to set the experience new power levels need from the leveling curves
+///
+/// Uses the entity's own `LevelingCurves` if it has them, otherwise the global ones.
+#[allow(clippy::type_complexity)]
+pub fn init_power_level<R: PowerResource>(
+    default_curves: Res<LevelingCurves>,
+    mut query: Query<(&mut PowerLevel<R>, Option<&LevelingCurves>), Added<PowerLevel<R>>>,
+) {
+    for (mut power_level, curves) in query.iter_mut() {
+        let curves = curves.unwrap_or(&default_curves);
+        power_level.experience_to_next = curves.experience_to_next.evaluate(power_level.level);
+    }
+}
+
+/// Most levels an entity gains in one run of `handle_level_up`
+const MAX_LEVEL_UPS_PER_RUN: u32 = 100;
+
 /// System to handle level up mechanics
-pub fn handle_level_up(
-    mut query: Query<(&mut PowerBar, &mut PowerLevel)>,
-    mut level_up_events: MessageWriter<LevelUpEvent>,
-) {
-    for (mut power_bar, mut power_level) in query.iter_mut() {
-        // This would be triggered by game events adding experience
-        // For demo purposes, we'll check if level up should occur
-        if power_level.experience >= power_level.experience_to_next {
-            let power_bonus = power_level.level_up();
+///
+/// Runs through every level the gathered experience allows and sends one
+/// `LevelUpEvent` per level gained. At most `MAX_LEVEL_UPS_PER_RUN` levels are
+/// gained per run, the rest follow in later runs.
+#[allow(clippy::type_complexity)]
+pub fn handle_level_up<R: PowerResource>(
+    default_curves: Res<LevelingCurves>,
+    mut query: Query<(
+        Entity,
+        &mut PowerBar<R>,
+        &mut PowerLevel<R>,
+        Option<&mut PowerLimits<R>>,
+        Option<&LevelingCurves>,
+    )>,
+    mut level_up_events: MessageWriter<LevelUpEvent<R>>,
+    mut changed_events: MessageWriter<PowerChangedEvent<R>>,
+) {
+    for (entity, mut power_bar, mut power_level, mut limits, curves) in query.iter_mut() {
+        let curves = curves.unwrap_or(&default_curves);
+        for _ in 0..MAX_LEVEL_UPS_PER_RUN {
+            if !power_level.can_level_up() {
+                break;
+            }
+            let power_bonus = power_level.level_up_with(curves);
+            let before = (power_bar.current, power_bar.max);
             power_bar.base_max += power_bonus;
-            power_bar.max += power_bonus;
+            power_bar.recalculate_max(limits.as_deref_mut());
+            write_power_changed(
+                &mut changed_events,
+                entity,
+                before,
+                &power_bar,
+                PowerChangeCause::LevelUp,
+            );
 
             level_up_events.write(LevelUpEvent {
-                entity: Entity::PLACEHOLDER,
+                entity,
                 new_level: power_level.level,
                 power_bonus,
+                resource: PhantomData,
             });
         }
     }
 }
 
+/// System to keep max power in sync with base_max and limits
+///
+/// Catches direct edits to `base_max` or `PowerLimits` made outside the power events.
+#[allow(clippy::type_complexity)]
+pub fn sync_power_max<R: PowerResource>(
+    mut query: Query<
+        (Entity, &mut PowerBar<R>, Option<&mut PowerLimits<R>>),
+        Or<(Changed<PowerBar<R>>, Changed<PowerLimits<R>>)>,
+    >,
+    mut changed_events: MessageWriter<PowerChangedEvent<R>>,
+) {
+    for (entity, mut power_bar, mut limits) in query.iter_mut() {
+        let base_max = power_bar.base_max;
+        if let Some(limits) = limits.as_mut() {
+            if limits
+                .limits
+                .iter()
+                .any(|l| l.power_value != l.value_for(base_max))
+            {
+                limits.recalculate(base_max);
+            }
+        }
+
+        let total_reduction = limits.map(|l| l.total_reduction()).unwrap_or(0.0);
+        let max = (base_max - total_reduction).max(0.0);
+        let current = power_bar.current.min(max);
+        if power_bar.max != max || power_bar.current != current {
+            let before = (power_bar.current, power_bar.max);
+            power_bar.max = max;
+            power_bar.current = current;
+            write_power_changed(
+                &mut changed_events,
+                entity,
+                before,
+                &power_bar,
+                PowerChangeCause::Recalculate,
+            );
+        }
+    }
+}
+
 /// System to detect knockout conditions
-pub fn detect_knockout(
-    mut query: Query<(Entity, &mut PowerBar), Changed<PowerBar>>,
-    mut knocked_out_events: MessageWriter<KnockedOutEvent>,
+///
+/// Accumulating resources never knock out, see `detect_overload`.
+#[allow(clippy::type_complexity)]
+pub fn detect_knockout<R: PowerResource>(
+    mut commands: Commands,
+    settings: Res<PowerSettings<R>>,
+    mut query: Query<
+        (
+            Entity,
+            &mut PowerBar<R>,
+            Option<&KnockoutPolicy>,
+            Option<&GracePolicy>,
+            Option<&mut GraceWindow<R>>,
+        ),
+        Changed<PowerBar<R>>,
+    >,
+    mut knocked_out_events: MessageWriter<KnockedOutEvent<R>>,
+    mut downed_events: MessageWriter<DownedEvent<R>>,
+    mut changed_events: MessageWriter<PowerChangedEvent<R>>,
+    mut grace_events: MessageWriter<GraceStartedEvent<R>>,
 ) {
-    for (entity, mut power_bar) in query.iter_mut() {
+    if R::DIRECTION == PowerDirection::Accumulating {
+        return;
+    }
+
+    for (entity, mut power_bar, policy, grace_policy, grace) in query.iter_mut() {
         if !power_bar.is_knocked_out && (power_bar.current <= 0.0 || power_bar.max <= 0.0) {
+            let before = (power_bar.current, power_bar.max);
             power_bar.is_knocked_out = true;
             power_bar.current = 0.0;
-            knocked_out_events.write(KnockedOutEvent { entity });
+            write_power_changed(
+                &mut changed_events,
+                entity,
+                before,
+                &power_bar,
+                PowerChangeCause::Knockout,
+            );
+
+            let policy = policy.copied().unwrap_or(settings.default_knockout_policy);
+            let state = KnockoutState::<R>::for_policy(policy);
+            match policy {
+                KnockoutPolicy::Downed { bleed_out } => {
+                    downed_events.write(DownedEvent {
+                        entity,
+                        bleed_out,
+                        resource: PhantomData,
+                    });
+                }
+                _ => {
+                    knocked_out_events.write(KnockedOutEvent {
+                        entity,
+                        resource: PhantomData,
+                    });
+                }
+            }
+            commands.entity(entity).insert(state);
+
+            // Optionally protect entities knocked out by limits from further limits
+            if let Some(grace_policy) = grace_policy {
+                if grace_policy.after_limit_knockout && power_bar.max <= 0.0 {
+                    start_grace(
+                        &mut commands,
+                        &mut grace_events,
+                        entity,
+                        grace_policy,
+                        grace,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// System to detect accumulating resources overloading and cooling down
+///
+/// A resource overloads when it fills up to max and stays overloaded, refusing
+/// spends, until regeneration has decayed it back to zero.
+pub fn detect_overload<R: PowerResource>(
+    mut query: Query<(Entity, &mut PowerBar<R>), Changed<PowerBar<R>>>,
+    mut overloaded_events: MessageWriter<OverloadedEvent<R>>,
+    mut cleared_events: MessageWriter<OverloadClearedEvent<R>>,
+    mut changed_events: MessageWriter<PowerChangedEvent<R>>,
+) {
+    if R::DIRECTION == PowerDirection::Depleting {
+        return;
+    }
+
+    for (entity, mut power_bar) in query.iter_mut() {
+        if !power_bar.is_overloaded && power_bar.remaining() <= 0.0 {
+            let before = (power_bar.current, power_bar.max);
+            power_bar.is_overloaded = true;
+            power_bar.current = power_bar.max;
+            write_power_changed(
+                &mut changed_events,
+                entity,
+                before,
+                &power_bar,
+                PowerChangeCause::Overload,
+            );
+            overloaded_events.write(OverloadedEvent {
+                entity,
+                resource: PhantomData,
+            });
+        } else if power_bar.is_overloaded && power_bar.current <= 0.0 && power_bar.max > 0.0 {
+            power_bar.is_overloaded = false;
+            cleared_events.write(OverloadClearedEvent {
+                entity,
+                resource: PhantomData,
+            });
+        }
+    }
+}
+
+/// System to advance knockout stages: bleeding out and timed auto-revives
+///
+/// Also removes the `KnockoutState` of revived entities. Timers pause while a
+/// `ReviveAttempt` is in progress on the entity.
+#[allow(clippy::type_complexity)]
+pub fn update_knockout_timers<R: PowerResource>(
+    time: Res<Time<PowerClock<R>>>,
+    settings: Res<PowerSettings<R>>,
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &PowerBar<R>,
+        &mut KnockoutState<R>,
+        Option<&KnockoutPolicy>,
+        Has<ReviveAttempt<R>>,
+    )>,
+    mut knocked_out_events: MessageWriter<KnockedOutEvent<R>>,
+    mut revive_events: MessageWriter<ReviveEvent<R>>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, power_bar, mut state, policy, being_revived) in query.iter_mut() {
+        if !power_bar.is_knocked_out {
+            commands.entity(entity).remove::<KnockoutState<R>>();
+            continue;
+        }
+        // Timers hold while someone is channelling a revive
+        if being_revived {
+            continue;
+        }
+        let finished = state.update(delta);
+
+        match state.stage {
+            KnockoutStage::Downed => {
+                if finished {
+                    // Bled out
+                    state.stage = KnockoutStage::KnockedOut;
+                    state.timer = None;
+                    knocked_out_events.write(KnockedOutEvent {
+                        entity,
+                        resource: PhantomData,
+                    });
+                }
+            }
+            KnockoutStage::KnockedOut => {
+                // A finished auto-revive stays pending while limits hold max at zero,
+                // reviving then would only knock the entity out again
+                let pending = state.timer.as_ref().is_some_and(Timer::is_finished);
+                let policy = policy.unwrap_or(&settings.default_knockout_policy);
+                if let KnockoutPolicy::AutoRevive { power, .. } = *policy {
+                    if pending && power_bar.max > 0.0 {
+                        revive_events.write(ReviveEvent {
+                            entity,
+                            power_amount: power,
+                            resource: PhantomData,
+                        });
+                    }
+                }
+            }
         }
     }
 }
@@ -1,12 +1,101 @@
 use crate::{
     components::{PowerBar, PowerRegeneration},
+    grace::GraceWindow,
     limits::PowerLimits,
+    regen::RegenModifiers,
+    resource::{Power, PowerDirection, PowerResource},
+    systems::PowerSystemSet,
 };
 use bevy::prelude::*;
+use std::marker::PhantomData;
 
-/// UI component for the power bar display
-#[derive(Component)]
-pub struct PowerBarUI;
+/// Plugin for the built-in power bars of resource `R`
+///
+/// Needs the `ui` feature and a UI capable app, e.g. `DefaultPlugins`. Added
+/// by `PowerSystemPlugin`, or next to a `PowerCorePlugin` of the same resource.
+pub struct PowerUiPlugin<R: PowerResource = Power> {
+    /// Whether every new `PowerBar` gets a bar widget
+    ///
+    /// When off, only bars placed with `spawn_power_bar_ui` are shown.
+    pub spawn_default_ui: bool,
+    /// Resource whose bars are shown
+    pub resource: PhantomData<R>,
+}
+
+impl<R: PowerResource> Default for PowerUiPlugin<R> {
+    fn default() -> Self {
+        Self {
+            spawn_default_ui: true,
+            resource: PhantomData,
+        }
+    }
+}
+
+impl<R: PowerResource> Plugin for PowerUiPlugin<R> {
+    fn build(&self, app: &mut App) {
+        app.register_type::<PowerBarUI<R>>()
+            .register_type::<NoPowerBarUI>();
+
+        // Screen slots of the automatic bars, shared by the plugins of every resource
+        app.init_resource::<PowerBarSlots>();
+
+        if self.spawn_default_ui {
+            app.add_systems(Update, setup_power_ui::<R>.in_set(PowerSystemSet::UI));
+        }
+        app.add_systems(
+            Update,
+            (despawn_orphaned_power_ui::<R>, update_power_bar_ui::<R>)
+                .chain()
+                .after(setup_power_ui::<R>)
+                .in_set(PowerSystemSet::UI),
+        );
+    }
+}
+
+/// UI component for the power bar display, bound to the entity whose power it shows
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component, Debug, Clone)]
+pub struct PowerBarUI<R: PowerResource = Power> {
+    /// Entity with the PowerBar component this widget displays
+    pub target: Entity,
+    /// Resource this widget displays
+    #[reflect(ignore)]
+    pub resource: PhantomData<R>,
+}
+
+/// Opt an entity out of the automatically spawned bars of every resource
+#[derive(Component, Reflect, Debug, Clone, Copy, Default)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct NoPowerBarUI;
+
+/// Screen slots of the automatically spawned bars, shared by every resource
+#[derive(Resource, Default)]
+pub(crate) struct PowerBarSlots(Vec<Option<Entity>>);
+
+impl PowerBarSlots {
+    /// Index of the topmost free slot
+    fn next_free(&self) -> usize {
+        self.0
+            .iter()
+            .position(Option::is_none)
+            .unwrap_or(self.0.len())
+    }
+
+    /// Assign a bar widget to a slot
+    fn occupy(&mut self, slot: usize, bar: Entity) {
+        if slot >= self.0.len() {
+            self.0.resize(slot + 1, None);
+        }
+        self.0[slot] = Some(bar);
+    }
+
+    /// Free the slot of a despawned bar widget
+    fn release(&mut self, bar: Entity) {
+        if let Some(slot) = self.0.iter_mut().find(|slot| **slot == Some(bar)) {
+            *slot = None;
+        }
+    }
+}
 
 /// Component marking the fill portion of the power bar
 #[derive(Component)]
@@ -20,12 +109,24 @@ pub struct PowerBarBackground;
 #[derive(Component)]
 pub struct PowerLimitSegment;
 
+/// Component marking the regeneration ceiling marker in the power bar
+#[derive(Component)]
+pub struct PowerRegenCeilingMarker;
+
 /// Component marking the power text display
 #[derive(Component)]
 pub struct PowerTextDisplay;
 
-/// Setup the power bar UI
-pub fn setup_power_ui(mut commands: Commands) {
+/// Spawn a power bar widget for `target` at the given screen position
+///
+/// Returns the root entity of the widget. The widget is despawned automatically
+/// once `target` no longer has a `PowerBar`.
+pub fn spawn_power_bar_ui<R: PowerResource>(
+    commands: &mut Commands,
+    target: Entity,
+    left: Val,
+    top: Val,
+) -> Entity {
     use bevy::ui::*;
 
     // Root UI container
@@ -33,14 +134,17 @@ pub fn setup_power_ui(mut commands: Commands) {
         .spawn(Node {
             width: Val::Px(304.0),
             height: Val::Px(40.0),
-            left: Val::Px(20.0),
-            top: Val::Px(20.0),
+            left,
+            top,
             position_type: PositionType::Absolute,
             padding: UiRect::all(Val::Px(2.0)),
             ..default()
         })
         .insert(BackgroundColor(Color::srgb(0.1, 0.1, 0.1)))
-        .insert(PowerBarUI)
+        .insert(PowerBarUI::<R> {
+            target,
+            resource: PhantomData,
+        })
         .with_children(|parent| {
             // Border/frame (pixelart style)
             parent
@@ -75,6 +179,18 @@ pub fn setup_power_ui(mut commands: Commands) {
                         })
                         .insert(BackgroundColor(Color::srgb(0.0, 0.8, 0.2)))
                         .insert(PowerBarFill);
+
+                    // Regeneration ceiling marker, hidden until a ceiling is set
+                    parent
+                        .spawn(Node {
+                            width: Val::Px(2.0),
+                            height: Val::Percent(100.0),
+                            position_type: PositionType::Absolute,
+                            display: Display::None,
+                            ..default()
+                        })
+                        .insert(BackgroundColor(Color::srgb(0.9, 0.9, 0.6)))
+                        .insert(PowerRegenCeilingMarker);
                 });
 
             // Text overlay (outside the frame so it's always visible)
@@ -98,69 +214,159 @@ pub fn setup_power_ui(mut commands: Commands) {
                         })
                         .insert(TextColor(Color::WHITE));
                 });
-        });
+        })
+        .id()
 }
 
-/// Update the power bar UI based on power state
-pub fn update_power_bar_ui(
-    power_query: Query<(&PowerBar, Option<&PowerLimits>, &PowerRegeneration)>,
-    mut fill_query: Query<&mut Node, (With<PowerBarFill>, Without<PowerBarBackground>)>,
-    mut bg_query: Query<&mut BackgroundColor, With<PowerBarFill>>,
-    mut text_query: Query<&mut Text, With<PowerTextDisplay>>,
+/// Spawn a bar for every new PowerBar, stacked down the left side of the screen
+///
+/// Bars of every resource share the stack, so an entity with several
+/// resources gets one bar per resource.
+pub fn setup_power_ui<R: PowerResource>(
     mut commands: Commands,
-    frame_query: Query<Entity, With<PowerBarBackground>>,
-    existing_segments: Query<Entity, With<PowerLimitSegment>>,
+    mut slots: ResMut<PowerBarSlots>,
+    new_power_bars: Query<Entity, (Added<PowerBar<R>>, Without<NoPowerBarUI>)>,
+    existing_bars: Query<&PowerBarUI<R>>,
 ) {
-    // Get the first power bar entity (for single player)
-    let Ok((power_bar, limits, regen)) = power_query.single() else {
-        return;
-    };
-
-    // Update fill width - show current power relative to base_max
-    if let Ok(mut node) = fill_query.single_mut() {
-        let fill_percentage = if power_bar.base_max > 0.0 {
-            (power_bar.current / power_bar.base_max * 100.0).clamp(0.0, 100.0)
-        } else {
-            0.0
-        };
-        node.width = Val::Percent(fill_percentage);
+    for target in new_power_bars.iter() {
+        if existing_bars.iter().any(|ui| ui.target == target) {
+            continue;
+        }
+        let slot = slots.next_free();
+        let top = 20.0 + slot as f32 * 50.0;
+        let bar = spawn_power_bar_ui::<R>(&mut commands, target, Val::Px(20.0), Val::Px(top));
+        slots.occupy(slot, bar);
     }
+}
 
-    // Update fill color based on state
-    if let Ok(mut bg_color) = bg_query.single_mut() {
-        bg_color.0 = if power_bar.is_knocked_out {
-            Color::srgb(0.5, 0.0, 0.0) // Red when knocked out
-        } else if regen.is_active {
-            Color::srgb(0.0, 0.9, 0.4) // Bright green when regenerating
-        } else if power_bar.percentage() < 0.3 {
-            Color::srgb(0.8, 0.4, 0.0) // Orange when low
-        } else {
-            Color::srgb(0.0, 0.8, 0.2) // Normal green
-        };
+/// Despawn bar widgets whose target no longer has a PowerBar
+pub fn despawn_orphaned_power_ui<R: PowerResource>(
+    mut commands: Commands,
+    mut slots: ResMut<PowerBarSlots>,
+    bars: Query<(Entity, &PowerBarUI<R>)>,
+    power_query: Query<(), With<PowerBar<R>>>,
+) {
+    for (entity, ui) in bars.iter() {
+        if power_query.get(ui.target).is_err() {
+            commands.entity(entity).despawn();
+            slots.release(entity);
+        }
     }
+}
 
-    // Update text - show current/max but also indicate base_max if different
-    if let Ok(mut text) = text_query.single_mut() {
-        **text = if power_bar.is_knocked_out {
-            "KNOCKED OUT".to_string()
-        } else if power_bar.max < power_bar.base_max {
-            format!(
-                "{:.0} / {:.0} ({:.0})",
-                power_bar.current, power_bar.max, power_bar.base_max
-            )
-        } else {
-            format!("{:.0} / {:.0}", power_bar.current, power_bar.max)
+/// Update every power bar widget based on its target's power state
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn update_power_bar_ui<R: PowerResource>(
+    bars: Query<(Entity, &PowerBarUI<R>)>,
+    power_query: Query<(
+        &PowerBar<R>,
+        Option<&PowerLimits<R>>,
+        Option<&PowerRegeneration<R>>,
+        Option<&RegenModifiers<R>>,
+        Option<&GraceWindow<R>>,
+    )>,
+    children_query: Query<&Children>,
+    mut fill_query: Query<(&mut Node, &mut BackgroundColor), With<PowerBarFill>>,
+    mut text_query: Query<&mut Text, With<PowerTextDisplay>>,
+    mut ceiling_query: Query<&mut Node, (With<PowerRegenCeilingMarker>, Without<PowerBarFill>)>,
+    frame_query: Query<Entity, With<PowerBarBackground>>,
+    segment_query: Query<Entity, With<PowerLimitSegment>>,
+    mut commands: Commands,
+) {
+    for (root, ui) in bars.iter() {
+        let Ok((power_bar, limits, regen, modifiers, grace)) = power_query.get(ui.target) else {
+            continue;
         };
-    }
+        // Regeneration only shows while the modified rate actually adds power
+        let regenerating = regen.is_some_and(|regen| {
+            regen.is_active
+                && modifiers.map_or(regen.current_rate, |m| m.apply(regen.current_rate)) > 0.0
+        });
 
-    // Clean up existing limit segments
-    for entity in existing_segments.iter() {
-        commands.entity(entity).despawn();
-    }
+        let mut frame = None;
+        for descendant in children_query.iter_descendants(root) {
+            // Update fill width and color
+            if let Ok((mut node, mut bg_color)) = fill_query.get_mut(descendant) {
+                // Show current power relative to base_max
+                let fill_percentage = if power_bar.base_max > 0.0 {
+                    (power_bar.current / power_bar.base_max * 100.0).clamp(0.0, 100.0)
+                } else {
+                    0.0
+                };
+                node.width = Val::Percent(fill_percentage);
+
+                bg_color.0 = if power_bar.is_knocked_out {
+                    Color::srgb(0.5, 0.0, 0.0) // Red when knocked out
+                } else if power_bar.is_overloaded {
+                    Color::srgb(1.0, 0.3, 0.0) // Hot orange-red when overloaded
+                } else if regenerating {
+                    Color::srgb(0.0, 0.9, 0.4) // Bright green when regenerating
+                } else if power_bar.max <= 0.0 || power_bar.remaining() / power_bar.max < 0.3 {
+                    Color::srgb(0.8, 0.4, 0.0) // Orange when low
+                } else {
+                    Color::srgb(0.0, 0.8, 0.2) // Normal green
+                };
 
-    // Handle limit segments
-    if let Some(limits) = limits {
-        if frame_query.single().is_ok() {
+                // Flash the fill while a grace window is active
+                if let Some(grace) = grace {
+                    let phase = (grace.timer.elapsed_secs() * 8.0) as u32;
+                    if phase.is_multiple_of(2) {
+                        bg_color.0 = Color::srgb(0.9, 0.9, 1.0);
+                    }
+                }
+            }
+
+            // Place the regeneration ceiling marker, relative to base_max like the fill
+            if let Ok(mut node) = ceiling_query.get_mut(descendant) {
+                match regen {
+                    Some(regen) if regen.ceiling.is_some() && power_bar.base_max > 0.0 => {
+                        let ceiling = match R::DIRECTION {
+                            PowerDirection::Depleting => regen.ceiling_for(power_bar.max),
+                            PowerDirection::Accumulating => {
+                                power_bar.max - regen.ceiling_for(power_bar.max)
+                            }
+                        } / power_bar.base_max;
+                        node.left = Val::Percent((ceiling * 100.0).clamp(0.0, 100.0));
+                        node.display = Display::Flex;
+                    }
+                    _ => node.display = Display::None,
+                }
+            }
+
+            // Update text - show current/max but also indicate base_max if different
+            if let Ok(mut text) = text_query.get_mut(descendant) {
+                **text = if power_bar.is_knocked_out {
+                    "KNOCKED OUT".to_string()
+                } else if power_bar.is_overloaded {
+                    "OVERLOADED".to_string()
+                } else if power_bar.max < power_bar.base_max {
+                    format!(
+                        "{:.0} / {:.0} ({:.0})",
+                        power_bar.current, power_bar.max, power_bar.base_max
+                    )
+                } else {
+                    format!("{:.0} / {:.0}", power_bar.current, power_bar.max)
+                };
+            }
+
+            if frame_query.get(descendant).is_ok() {
+                frame = Some(descendant);
+            }
+        }
+
+        let Some(frame_entity) = frame else {
+            continue;
+        };
+
+        // Clean up existing limit segments of this bar
+        for child in children_query.iter_descendants(frame_entity) {
+            if segment_query.get(child).is_ok() {
+                commands.entity(child).despawn();
+            }
+        }
+
+        // Handle limit segments
+        if let Some(limits) = limits {
             // Create limit segments that show missing power from the right side
             let segments = limits.get_limit_segments(power_bar.base_max);
             let mut offset_from_right = 0.0;
@@ -171,21 +377,19 @@ pub fn update_power_bar_ui(
 
                 if segment_width > 0.0 {
                     // Add segments to the power bar frame
-                    if let Ok(frame_entity) = frame_query.single() {
-                        commands.entity(frame_entity).with_children(|parent| {
-                            parent
-                                .spawn(Node {
-                                    width: Val::Px(segment_width),
-                                    height: Val::Percent(100.0),
-                                    right: Val::Px(offset_from_right),
-                                    top: Val::Px(0.0),
-                                    position_type: PositionType::Absolute,
-                                    ..default()
-                                })
-                                .insert(BackgroundColor(color.with_alpha(0.7)))
-                                .insert(PowerLimitSegment);
-                        });
-                    }
+                    commands.entity(frame_entity).with_children(|parent| {
+                        parent
+                            .spawn(Node {
+                                width: Val::Px(segment_width),
+                                height: Val::Percent(100.0),
+                                right: Val::Px(offset_from_right),
+                                top: Val::Px(0.0),
+                                position_type: PositionType::Absolute,
+                                ..default()
+                            })
+                            .insert(BackgroundColor(color.with_alpha(0.7)))
+                            .insert(PowerLimitSegment);
+                    });
 
                     offset_from_right += segment_width;
 
@@ -198,6 +402,3 @@ pub fn update_power_bar_ui(
         }
     }
 }
-
-// Helper function for creating pixelart borders can be added here if needed
-// Currently not used in the implementation
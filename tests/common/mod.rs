@@ -0,0 +1,30 @@
+//! Setup shared by the integration tests
+#![allow(dead_code)]
+
+use bevy::app::Plugins;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_power::prelude::*;
+
+/// Headless app with the given power plugins, advancing 250ms per update
+pub fn app_with<M>(plugins: impl Plugins<M>) -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins).add_plugins(plugins);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(
+        std::time::Duration::from_millis(250),
+    ));
+    app
+}
+
+/// Headless app with the default `PowerSystemPlugin`, advancing 250ms per update
+pub fn app() -> App {
+    app_with(PowerSystemPlugin::<Power>::default())
+}
+
+/// Take every message of type `M` sent since the last drain
+pub fn drain<M: Message + Clone>(app: &mut App) -> Vec<M> {
+    app.world_mut()
+        .resource_mut::<Messages<M>>()
+        .drain()
+        .collect()
+}
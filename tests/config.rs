@@ -0,0 +1,142 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_power::prelude::*;
+use common::{app, app_with, drain};
+
+#[test]
+fn default_regen_applies_to_bars_without_custom_regen() {
+    let mut app = app_with(PowerSystemPlugin::<Power> {
+        default_regen: PowerRegeneration {
+            regen_delay: 0.5,
+            curve: RegenCurve::Constant,
+            base_rate: 40.0,
+            ..default()
+        },
+        ..default()
+    });
+    let default_bar = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    let custom_bar = app
+        .world_mut()
+        .spawn(PowerBundle::<Power>::custom(100.0, 5.0, 40.0, 40.0))
+        .id();
+    // Explicit regenerations are kept even when they match the crate defaults
+    let explicit_bar = app
+        .world_mut()
+        .spawn(PowerBundle::<Power>::custom(100.0, 2.5, 5.0, 20.0))
+        .id();
+    let bare_bar = app.world_mut().spawn(PowerBar::<Power>::new(50.0)).id();
+    app.update();
+
+    let regen = app.world().get::<PowerRegeneration>(default_bar).unwrap();
+    assert_eq!((regen.regen_delay, regen.base_rate), (0.5, 40.0));
+    let regen = app.world().get::<PowerRegeneration>(custom_bar).unwrap();
+    assert_eq!(regen.regen_delay, 5.0);
+    let regen = app.world().get::<PowerRegeneration>(explicit_bar).unwrap();
+    assert_eq!((regen.regen_delay, regen.base_rate), (2.5, 5.0));
+    let regen = app.world().get::<PowerRegeneration>(bare_bar).unwrap();
+    assert_eq!(regen.regen_delay, 0.5);
+
+    // Runtime changes to the settings reach bars spawned afterwards
+    app.world_mut()
+        .resource_mut::<PowerSettings>()
+        .default_regen
+        .regen_delay = 1.0;
+    let late_bar = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+    let regen = app.world().get::<PowerRegeneration>(late_bar).unwrap();
+    assert_eq!(regen.regen_delay, 1.0);
+}
+
+#[test]
+fn default_knockout_policy_applies_without_a_component() {
+    let mut app = app_with(PowerSystemPlugin::<Power> {
+        default_knockout_policy: KnockoutPolicy::AutoRevive {
+            delay: 0.5,
+            power: 40.0,
+        },
+        ..default()
+    });
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::drain(entity, 100.0));
+    app.update();
+    assert!(app.world().get::<PowerBar>(entity).unwrap().is_knocked_out);
+    assert_eq!(drain::<KnockedOutEvent>(&mut app).len(), 1);
+
+    for _ in 0..3 {
+        app.update();
+    }
+    let power_bar = app.world().get::<PowerBar>(entity).unwrap();
+    assert!(!power_bar.is_knocked_out);
+    assert_eq!(power_bar.current, 40.0);
+}
+
+#[test]
+fn real_time_source_keeps_running_while_paused() {
+    let mut app = app_with(PowerSystemPlugin::<Power> {
+        time_source: PowerTimeSource::Real,
+        ..default()
+    });
+    let entity = app
+        .world_mut()
+        .spawn(PowerBundle::<Power>::custom(100.0, 0.5, 40.0, 40.0))
+        .id();
+    app.update();
+    app.world_mut().resource_mut::<Time<Virtual>>().pause();
+
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Power>::new(entity, 30.0));
+    app.update();
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().current, 70.0);
+
+    app.update();
+    app.update();
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().current, 90.0);
+    assert_eq!(app.world().resource::<Time<Virtual>>().delta_secs(), 0.0);
+}
+
+#[test]
+fn schedule_time_source_stops_while_paused() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn(PowerBundle::<Power>::custom(100.0, 0.5, 40.0, 40.0))
+        .id();
+    app.update();
+    app.world_mut().resource_mut::<Time<Virtual>>().pause();
+
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Power>::new(entity, 30.0));
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().current, 70.0);
+}
+
+#[cfg(feature = "ui")]
+#[test]
+fn default_ui_can_be_turned_off() {
+    use bevy::ecs::system::RunSystemOnce;
+
+    let mut app = app_with(PowerSystemPlugin::<Power> {
+        spawn_default_ui: false,
+        ..default()
+    });
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+    let world = app.world_mut();
+    assert_eq!(world.query::<&PowerBarUI>().iter(world).count(), 0);
+
+    // Bars placed by hand still update
+    app.world_mut()
+        .run_system_once(move |mut commands: Commands| {
+            spawn_power_bar_ui::<Power>(&mut commands, entity, Val::Px(20.0), Val::Px(20.0));
+        })
+        .unwrap();
+    app.update();
+    let world = app.world_mut();
+    assert_eq!(world.query::<&PowerBarUI>().iter(world).count(), 1);
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy_power::prelude::*;
+use common::{app, drain};
+
+#[test]
+fn drains_tick_every_frame_and_pause_regen() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+    app.update();
+
+    app.world_mut()
+        .run_system_once(|mut power: PowerSystem| {
+            power.start_drain(1, 10.0);
+            power.start_drain_with(PowerDrain::new(2, 4.0).with_pauses_regen(false));
+        })
+        .unwrap();
+    app.update();
+    app.update();
+
+    let power_bar = app.world().get::<PowerBar>(entity).unwrap();
+    assert_eq!(power_bar.current, 93.0);
+    let regen = app.world().get::<PowerRegeneration>(entity).unwrap();
+    assert_eq!(regen.time_since_spend, 0.0);
+    let drains = app.world().get::<PowerDrains>(entity).unwrap();
+    assert_eq!(drains.total_rate(), 14.0);
+
+    app.world_mut()
+        .run_system_once(|mut power: PowerSystem| power.stop_drain(1))
+        .unwrap();
+    app.update();
+    let ended = drain::<DrainEndedEvent>(&mut app);
+    assert_eq!(ended.len(), 1);
+    assert_eq!((ended[0].id, ended[0].reason), (1, DrainEndReason::Stopped));
+}
+
+#[test]
+fn safe_drains_end_before_a_knockout() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    app.world_mut().write_message(StartDrainEvent::<Power>::new(
+        entity,
+        PowerDrain::new(1, 180.0),
+    ));
+    for _ in 0..4 {
+        app.update();
+    }
+
+    let power_bar = app.world().get::<PowerBar>(entity).unwrap();
+    assert!(!power_bar.is_knocked_out);
+    assert_eq!(power_bar.current, 10.0);
+    let ended = drain::<DrainEndedEvent>(&mut app);
+    assert_eq!(ended.len(), 1);
+    assert_eq!(ended[0].reason, DrainEndReason::Exhausted);
+    assert!(app
+        .world()
+        .get::<PowerDrains>(entity)
+        .unwrap()
+        .drains
+        .is_empty());
+}
+
+#[test]
+fn draining_drains_knock_out_and_end() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    let other = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::drain(other, 100.0));
+    app.update();
+
+    app.world_mut().write_message(StartDrainEvent::<Power>::new(
+        entity,
+        PowerDrain::new(1, 400.0).with_mode(ChangeMode::Drain),
+    ));
+    app.world_mut().write_message(StartDrainEvent::<Power>::new(
+        other,
+        PowerDrain::new(1, 1.0),
+    ));
+    app.update();
+
+    assert!(app.world().get::<PowerBar>(entity).unwrap().is_knocked_out);
+    assert!(app.world().get::<PowerBar>(other).unwrap().is_knocked_out);
+    let ended = drain::<DrainEndedEvent>(&mut app);
+    assert_eq!(ended.len(), 2);
+    let reason = |target| {
+        ended
+            .iter()
+            .find(|event| event.entity == target)
+            .map(|event| event.reason)
+    };
+    assert_eq!(reason(entity), Some(DrainEndReason::Exhausted));
+    assert_eq!(reason(other), Some(DrainEndReason::KnockedOut));
+}
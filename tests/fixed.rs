@@ -0,0 +1,110 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_power::prelude::*;
+use common::app_with;
+use std::time::Duration;
+
+/// App running the power logic at 20 fixed ticks per second, with frames of `frame_ms`
+fn app(frame_ms: u64) -> App {
+    let mut app = app_with(PowerSystemPlugin::<Power>::fixed());
+    app.insert_resource(Time::<Fixed>::from_hz(20.0));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        frame_ms,
+    )));
+    app
+}
+
+/// Update until `secs` seconds of fixed time have run
+fn run_until(app: &mut App, secs: f32) {
+    while app.world().resource::<Time<Fixed>>().elapsed_secs() < secs {
+        app.update();
+    }
+}
+
+/// Play the same input sequence and return the final bar and regeneration
+fn simulate(frame_ms: u64) -> (PowerBar, PowerRegeneration) {
+    let mut app = app(frame_ms);
+    let entity = app
+        .world_mut()
+        .spawn(PowerBundle::<Power>::custom(100.0, 0.4, 7.0, 23.0))
+        .id();
+
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Power>::new(entity, 35.0));
+    app.world_mut()
+        .write_message(ApplyLimitEvent::<Power>::points(
+            entity,
+            1,
+            15.0,
+            Color::WHITE,
+            Some(1.2),
+            false,
+        ));
+    run_until(&mut app, 1.5);
+
+    app.world_mut().write_message(StartDrainEvent::<Power>::new(
+        entity,
+        PowerDrain::new(1, 9.0),
+    ));
+    run_until(&mut app, 2.25);
+
+    app.world_mut()
+        .write_message(StopDrainEvent::<Power>::new(entity, 1));
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Power>::new(entity, 12.5));
+    run_until(&mut app, 4.5);
+
+    let world = app.world();
+    (
+        world.get::<PowerBar>(entity).unwrap().clone(),
+        world.get::<PowerRegeneration>(entity).unwrap().clone(),
+    )
+}
+
+#[test]
+fn results_do_not_depend_on_frame_splitting() {
+    let (bar, regen) = simulate(50);
+    assert!(bar.current > 0.0 && bar.current < bar.max);
+    assert_eq!(bar.max, 100.0);
+    assert!(regen.is_active);
+
+    for frame_ms in [150, 250] {
+        let (other_bar, other_regen) = simulate(frame_ms);
+        assert_eq!(other_bar.current.to_bits(), bar.current.to_bits());
+        assert_eq!(other_bar.max, bar.max);
+        assert_eq!(other_regen.current_rate, regen.current_rate);
+        assert_eq!(other_regen.time_since_spend, regen.time_since_spend);
+    }
+}
+
+#[test]
+fn systems_only_run_on_fixed_ticks() {
+    let mut app = app(10);
+    let entity = app
+        .world_mut()
+        .spawn(PowerBundle::<Power>::custom(100.0, 5.0, 20.0, 20.0))
+        .id();
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Power>::new(entity, 50.0));
+
+    // Frames shorter than a tick leave the bar alone until a tick has run
+    for _ in 0..4 {
+        app.update();
+        assert_eq!(app.world().get::<PowerBar>(entity).unwrap().current, 100.0);
+    }
+    run_until(&mut app, 0.05);
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().current, 50.0);
+}
+
+#[test]
+#[should_panic(expected = "fixed schedule")]
+fn frame_time_sources_are_rejected_in_fixed_schedules() {
+    App::new()
+        .add_plugins(MinimalPlugins)
+        .add_plugins(PowerSystemPlugin::<Power> {
+            time_source: PowerTimeSource::Virtual,
+            ..PowerSystemPlugin::fixed()
+        });
+}
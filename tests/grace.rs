@@ -0,0 +1,254 @@
+mod common;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_power::prelude::*;
+use common::{app, drain};
+
+/// Spawn an entity whose regeneration stays out of the way of the assertions
+fn spawn(app: &mut App, policy: GracePolicy) -> Entity {
+    let entity = app
+        .world_mut()
+        .spawn((PowerBundle::<Power>::custom(100.0, 100.0, 0.0, 0.0), policy))
+        .id();
+    app.update();
+    entity
+}
+
+fn knock_out_and_revive(app: &mut App, entity: Entity) {
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::drain(entity, 100.0));
+    app.update();
+    assert!(app.world().get::<PowerBar>(entity).unwrap().is_knocked_out);
+
+    app.world_mut()
+        .write_message(ReviveEvent::<Power>::new(entity, 50.0));
+    app.update();
+}
+
+fn current(app: &App, entity: Entity) -> f32 {
+    app.world().get::<PowerBar>(entity).unwrap().current
+}
+
+#[test]
+fn revive_grants_a_grace_window_that_blocks_damage() {
+    let mut app = app();
+    let entity = spawn(
+        &mut app,
+        GracePolicy {
+            duration: 1.0,
+            ..Default::default()
+        },
+    );
+
+    knock_out_and_revive(&mut app, entity);
+    assert!(app.world().get::<GraceWindow>(entity).is_some());
+    let started = drain::<GraceStartedEvent>(&mut app);
+    assert_eq!(started.len(), 1);
+    assert_eq!(started[0].duration, 1.0);
+
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::drain(entity, 30.0));
+    app.update();
+    assert_eq!(current(&app, entity), 50.0);
+
+    // Gains still go through
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::safe(entity, 10.0));
+    app.update();
+    assert_eq!(current(&app, entity), 60.0);
+
+    app.update();
+    assert!(app.world().get::<GraceWindow>(entity).is_none());
+    assert_eq!(drain::<GraceEndedEvent>(&mut app).len(), 1);
+
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::drain(entity, 30.0));
+    app.update();
+    assert_eq!(current(&app, entity), 30.0);
+}
+
+#[test]
+fn grace_window_can_reduce_damage_instead() {
+    let mut app = app();
+    let entity = spawn(
+        &mut app,
+        GracePolicy {
+            damage_scale: 0.5,
+            ..Default::default()
+        },
+    );
+
+    knock_out_and_revive(&mut app, entity);
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::drain(entity, 30.0));
+    app.update();
+    assert_eq!(current(&app, entity), 35.0);
+}
+
+#[test]
+fn limits_are_deferred_until_grace_ends() {
+    let mut app = app();
+    let entity = spawn(
+        &mut app,
+        GracePolicy {
+            duration: 1.0,
+            ..Default::default()
+        },
+    );
+
+    knock_out_and_revive(&mut app, entity);
+    app.world_mut()
+        .write_message(ApplyLimitEvent::<Power>::points(
+            entity,
+            1,
+            20.0,
+            Color::WHITE,
+            None,
+            false,
+        ));
+    app.update();
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().max, 100.0);
+    assert!(drain::<LimitAppliedEvent>(&mut app).is_empty());
+    assert_eq!(
+        app.world()
+            .get::<GraceWindow>(entity)
+            .unwrap()
+            .deferred_limits
+            .len(),
+        1
+    );
+
+    // Checked limits can't be deferred and are rejected instead
+    let result = app
+        .world_mut()
+        .run_system_once(move |mut power: PowerSystem| {
+            power
+                .entity(entity)
+                .try_limit_points(2, 10.0, Color::WHITE, None, false)
+        })
+        .unwrap();
+    assert_eq!(result, Err(PowerError::InGrace));
+    let rejected = drain::<LimitRejectedEvent>(&mut app);
+    assert_eq!(rejected.len(), 1);
+    assert_eq!(rejected[0].reason, FailureReason::InGrace);
+
+    // The window ends this frame and its limits are applied on the next one
+    app.update();
+    app.update();
+    app.update();
+    assert!(app.world().get::<GraceWindow>(entity).is_none());
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().max, 80.0);
+    assert_eq!(drain::<LimitAppliedEvent>(&mut app).len(), 1);
+}
+
+#[test]
+fn grace_window_can_block_spends() {
+    let mut app = app();
+    let entity = spawn(
+        &mut app,
+        GracePolicy {
+            blocks_spends: true,
+            ..Default::default()
+        },
+    );
+
+    knock_out_and_revive(&mut app, entity);
+    let result = app
+        .world_mut()
+        .run_system_once(move |mut power: PowerSystem| power.entity(entity).try_spend(10.0))
+        .unwrap();
+    assert_eq!(result, Err(PowerError::InGrace));
+    let failed = drain::<SpendFailedEvent>(&mut app);
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].reason, FailureReason::InGrace);
+
+    // Spends sent directly are rejected by the handler
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Power>::new(entity, 10.0));
+    app.update();
+    assert_eq!(current(&app, entity), 50.0);
+    assert_eq!(
+        drain::<SpendFailedEvent>(&mut app)[0].reason,
+        FailureReason::InGrace
+    );
+}
+
+#[test]
+fn grace_window_holds_back_drains() {
+    let mut app = app();
+    let damaged = spawn(
+        &mut app,
+        GracePolicy {
+            duration: 2.0,
+            ..Default::default()
+        },
+    );
+    let busy = spawn(
+        &mut app,
+        GracePolicy {
+            duration: 2.0,
+            damage_scale: 1.0,
+            blocks_spends: true,
+            ..Default::default()
+        },
+    );
+    knock_out_and_revive(&mut app, damaged);
+    knock_out_and_revive(&mut app, busy);
+
+    let bleed = PowerDrain::new(1, 100.0).with_mode(ChangeMode::Drain);
+    app.world_mut()
+        .write_message(StartDrainEvent::<Power>::new(damaged, bleed));
+    app.world_mut().write_message(StartDrainEvent::<Power>::new(
+        busy,
+        PowerDrain::new(1, 20.0),
+    ));
+    for _ in 0..2 {
+        app.update();
+    }
+    assert_eq!(current(&app, damaged), 50.0);
+    assert!(!app.world().get::<PowerBar>(damaged).unwrap().is_knocked_out);
+    assert_eq!(current(&app, busy), 50.0);
+    assert!(app.world().get::<PowerDrains>(busy).unwrap().is_active(1));
+
+    // Both drains resume once the window is over
+    for _ in 0..6 {
+        app.update();
+    }
+    assert!(app.world().get::<PowerBar>(damaged).unwrap().is_knocked_out);
+    assert!(current(&app, busy) < 50.0);
+}
+
+#[test]
+fn grace_after_limit_knockout_is_opt_in() {
+    let mut app = app();
+    let plain = spawn(&mut app, GracePolicy::default());
+    let guarded = spawn(
+        &mut app,
+        GracePolicy {
+            after_limit_knockout: true,
+            ..Default::default()
+        },
+    );
+
+    for entity in [plain, guarded] {
+        app.world_mut()
+            .write_message(ApplyLimitEvent::<Power>::percentage(
+                entity,
+                1,
+                100.0,
+                Color::WHITE,
+                None,
+                false,
+            ));
+    }
+    app.update();
+
+    assert!(app.world().get::<PowerBar>(plain).unwrap().is_knocked_out);
+    assert!(app.world().get::<PowerBar>(guarded).unwrap().is_knocked_out);
+    assert!(app.world().get::<GraceWindow>(plain).is_none());
+    assert!(app.world().get::<GraceWindow>(guarded).is_some());
+    let started = drain::<GraceStartedEvent>(&mut app);
+    assert_eq!(started.len(), 1);
+    assert_eq!(started[0].entity, guarded);
+}
@@ -0,0 +1,177 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_power::prelude::*;
+use common::{app, drain};
+
+fn knock_out_with_limit(app: &mut App, entity: Entity) {
+    app.world_mut()
+        .write_message(ApplyLimitEvent::<Power>::percentage(
+            entity,
+            1,
+            100.0,
+            Color::WHITE,
+            None,
+            false,
+        ));
+    app.update();
+}
+
+#[test]
+fn lifting_a_limit_does_not_revive_by_default() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    knock_out_with_limit(&mut app, entity);
+    assert_eq!(drain::<KnockedOutEvent>(&mut app).len(), 1);
+    assert!(app.world().get::<KnockoutState>(entity).is_some());
+
+    app.world_mut()
+        .write_message(LiftLimitEvent::<Power>::all(entity, 1));
+    app.update();
+    app.update();
+
+    let power_bar = app.world().get::<PowerBar>(entity).unwrap();
+    assert!(power_bar.is_knocked_out);
+    assert_eq!(power_bar.current, 0.0);
+    assert!(drain::<KnockedOutEvent>(&mut app).is_empty());
+}
+
+#[test]
+fn revive_on_limit_lift_policy() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            PowerBundle::<Power>::new(),
+            KnockoutPolicy::ReviveOnLimitLift { power: 25.0 },
+        ))
+        .id();
+    app.update();
+
+    knock_out_with_limit(&mut app, entity);
+    assert!(app.world().get::<KnockoutState>(entity).is_some());
+
+    app.world_mut()
+        .write_message(LiftLimitEvent::<Power>::all(entity, 1));
+    app.update();
+    app.update();
+
+    let power_bar = app.world().get::<PowerBar>(entity).unwrap();
+    assert!(!power_bar.is_knocked_out);
+    assert_eq!(power_bar.current, 25.0);
+    assert!(app.world().get::<KnockoutState>(entity).is_none());
+}
+
+#[test]
+fn auto_revive_after_delay() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            PowerBundle::<Power>::new(),
+            KnockoutPolicy::AutoRevive {
+                delay: 1.0,
+                power: 40.0,
+            },
+        ))
+        .id();
+    app.update();
+
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::drain(entity, 100.0));
+    app.update();
+    assert!(app.world().get::<PowerBar>(entity).unwrap().is_knocked_out);
+
+    for _ in 0..4 {
+        app.update();
+    }
+    assert!(app.world().get::<PowerBar>(entity).unwrap().is_knocked_out);
+
+    app.update();
+    let power_bar = app.world().get::<PowerBar>(entity).unwrap();
+    assert!(!power_bar.is_knocked_out);
+    assert_eq!(power_bar.current, 40.0);
+}
+
+#[test]
+fn auto_revive_waits_while_limits_hold_max_at_zero() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            PowerBundle::<Power>::new(),
+            KnockoutPolicy::AutoRevive {
+                delay: 1.0,
+                power: 40.0,
+            },
+        ))
+        .id();
+    app.update();
+
+    knock_out_with_limit(&mut app, entity);
+    let mut knockouts = drain::<KnockedOutEvent>(&mut app).len();
+    for _ in 0..20 {
+        app.update();
+        knockouts += drain::<KnockedOutEvent>(&mut app).len();
+        assert!(drain::<ReviveEvent>(&mut app).is_empty());
+    }
+    assert_eq!(knockouts, 1);
+    assert!(app.world().get::<PowerBar>(entity).unwrap().is_knocked_out);
+
+    app.world_mut()
+        .write_message(LiftLimitEvent::<Power>::all(entity, 1));
+    app.update();
+    app.update();
+    let power_bar = app.world().get::<PowerBar>(entity).unwrap();
+    assert!(!power_bar.is_knocked_out);
+    assert_eq!(power_bar.current, 40.0);
+    assert!(drain::<KnockedOutEvent>(&mut app).is_empty());
+}
+
+#[test]
+fn downed_entities_bleed_out_unless_revived() {
+    let mut app = app();
+    let bleeding = app
+        .world_mut()
+        .spawn((
+            PowerBundle::<Power>::new(),
+            KnockoutPolicy::Downed { bleed_out: 0.5 },
+        ))
+        .id();
+    let saved = app
+        .world_mut()
+        .spawn((
+            PowerBundle::<Power>::new(),
+            KnockoutPolicy::Downed { bleed_out: 0.5 },
+        ))
+        .id();
+    app.update();
+
+    for entity in [bleeding, saved] {
+        app.world_mut()
+            .write_message(PowerChangeEvent::<Power>::drain(entity, 100.0));
+    }
+    app.update();
+    assert_eq!(drain::<DownedEvent>(&mut app).len(), 2);
+    assert!(drain::<KnockedOutEvent>(&mut app).is_empty());
+    assert!(app
+        .world()
+        .get::<KnockoutState>(bleeding)
+        .unwrap()
+        .is_downed());
+
+    app.world_mut()
+        .write_message(ReviveEvent::<Power>::new(saved, 30.0));
+    app.update();
+    app.update();
+
+    let knocked_out = drain::<KnockedOutEvent>(&mut app);
+    assert_eq!(knocked_out.len(), 1);
+    assert_eq!(knocked_out[0].entity, bleeding);
+    let state = app.world().get::<KnockoutState>(bleeding).unwrap();
+    assert_eq!(state.stage, KnockoutStage::KnockedOut);
+    assert!(!app.world().get::<PowerBar>(saved).unwrap().is_knocked_out);
+    assert!(app.world().get::<KnockoutState>(saved).is_none());
+}
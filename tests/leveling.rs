@@ -0,0 +1,117 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_power::prelude::*;
+use common::{app, app_with};
+
+#[test]
+fn first_level_uses_the_configured_curves() {
+    let mut app = app_with(PowerSystemPlugin::<Power> {
+        leveling_curves: Some(LevelingCurves {
+            experience_to_next: LevelingCurve::Linear {
+                base: 50.0,
+                per_level: 25.0,
+            },
+            ..default()
+        }),
+        ..default()
+    });
+    let global = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    let own = app
+        .world_mut()
+        .spawn((
+            PowerBundle::<Power>::new(),
+            LevelingCurves {
+                experience_to_next: LevelingCurve::Table(vec![20.0]),
+                ..default()
+            },
+        ))
+        .id();
+    app.update();
+    let level = app.world().get::<PowerLevel>(global).unwrap();
+    assert_eq!(level.experience_to_next, 50.0);
+    assert_eq!(
+        app.world()
+            .get::<PowerLevel>(own)
+            .unwrap()
+            .experience_to_next,
+        20.0
+    );
+
+    app.world_mut()
+        .write_message(GainExperienceEvent::<Power>::new(global, 60.0));
+    app.update();
+    let level = app.world().get::<PowerLevel>(global).unwrap();
+    assert_eq!(level.level, 2);
+    assert_eq!(level.experience, 10.0);
+    assert_eq!(level.experience_to_next, 75.0);
+}
+
+#[test]
+fn large_gains_cross_several_levels() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    // 100 + 150 + 225 experience for levels 2 to 4, 25 carried over
+    app.world_mut()
+        .write_message(GainExperienceEvent::<Power>::new(entity, 500.0));
+    app.update();
+
+    let level = app.world().get::<PowerLevel>(entity).unwrap();
+    assert_eq!(level.level, 4);
+    assert_eq!(level.experience, 25.0);
+    assert_eq!(level.experience_to_next, 337.5);
+    let level_ups: Vec<_> = app
+        .world_mut()
+        .resource_mut::<Messages<LevelUpEvent>>()
+        .drain()
+        .map(|event| event.new_level)
+        .collect();
+    assert_eq!(level_ups, vec![2, 3, 4]);
+}
+
+#[test]
+fn huge_gains_level_up_over_several_frames() {
+    let mut app = app_with(PowerSystemPlugin::<Power> {
+        leveling_curves: Some(LevelingCurves {
+            experience_to_next: LevelingCurve::Table(vec![1.0]),
+            power_bonus: LevelingCurve::Table(vec![0.0]),
+        }),
+        ..default()
+    });
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    app.world_mut()
+        .write_message(GainExperienceEvent::<Power>::new(entity, 250.0));
+    app.update();
+    let level = app.world().get::<PowerLevel>(entity).unwrap();
+    assert_eq!((level.level, level.experience), (101, 150.0));
+
+    app.update();
+    app.update();
+    let level = app.world().get::<PowerLevel>(entity).unwrap();
+    assert_eq!((level.level, level.experience), (251, 0.0));
+}
+
+#[test]
+fn gains_beyond_f32_precision_do_not_hang() {
+    let mut app = app_with(PowerSystemPlugin::<Power> {
+        leveling_curves: Some(LevelingCurves {
+            experience_to_next: LevelingCurve::Table(vec![1.0]),
+            power_bonus: LevelingCurve::Table(vec![0.0]),
+        }),
+        ..default()
+    });
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    // Taking 1.0 off 1e8 leaves 1e8, so no level can be paid for
+    app.world_mut()
+        .write_message(GainExperienceEvent::<Power>::new(entity, 1.0e8));
+    app.update();
+    let level = app.world().get::<PowerLevel>(entity).unwrap();
+    assert_eq!(level.level, 1);
+    assert!(!level.can_level_up());
+}
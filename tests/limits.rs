@@ -0,0 +1,194 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_power::prelude::*;
+use common::{app, drain};
+
+fn limit(id: u32, points: f32, duration: Option<f32>, stacking: LimitStacking) -> PowerLimit {
+    PowerLimit::new(id, LimitType::Points(points), Color::WHITE, duration, false)
+        .with_stacking(stacking)
+}
+
+#[test]
+fn replace_keeps_a_single_entry() {
+    let mut limits = PowerLimits::<Power>::default();
+    limits.add_limit(limit(1, 10.0, None, LimitStacking::Replace), 100.0);
+    let outcome = limits.add_limit(limit(1, 25.0, None, LimitStacking::Replace), 100.0);
+
+    assert_eq!(outcome, LimitStackOutcome::Replaced);
+    assert_eq!(limits.stack_count(1), 1);
+    assert_eq!(limits.total_reduction(), 25.0);
+}
+
+#[test]
+fn refresh_and_extend_adjust_the_timer() {
+    let mut limits = PowerLimits::<Power>::default();
+    limits.add_limit(limit(1, 10.0, Some(4.0), LimitStacking::Replace), 100.0);
+    limits.update_timers(3.0);
+
+    let refresh = limit(1, 10.0, Some(4.0), LimitStacking::RefreshDuration);
+    assert_eq!(
+        limits.add_limit(refresh, 100.0),
+        LimitStackOutcome::Refreshed
+    );
+    assert_eq!(limits.limits[0].remaining_secs(), Some(4.0));
+
+    limits.update_timers(1.0);
+    let extend = limit(1, 10.0, Some(2.0), LimitStacking::ExtendDuration);
+    assert_eq!(limits.add_limit(extend, 100.0), LimitStackOutcome::Extended);
+    assert_eq!(limits.limits[0].remaining_secs(), Some(5.0));
+    assert_eq!(limits.stack_count(1), 1);
+}
+
+#[test]
+fn stack_respects_max_stacks() {
+    let mut limits = PowerLimits::<Power>::default();
+    let stacking = LimitStacking::Stack { max_stacks: 2 };
+    assert_eq!(
+        limits.add_limit(limit(3, 10.0, None, stacking), 100.0),
+        LimitStackOutcome::Added
+    );
+    assert_eq!(
+        limits.add_limit(limit(3, 10.0, None, stacking), 100.0),
+        LimitStackOutcome::Stacked
+    );
+    assert_eq!(
+        limits.add_limit(limit(3, 10.0, None, stacking), 100.0),
+        LimitStackOutcome::Ignored
+    );
+    assert_eq!(limits.total_reduction(), 20.0);
+
+    assert!(limits.remove_limit(3).is_some());
+    assert_eq!(limits.stack_count(3), 1);
+    assert_eq!(limits.remove_all(3).len(), 1);
+    assert_eq!(limits.stack_count(3), 0);
+}
+
+#[test]
+fn ignore_keeps_the_active_limit() {
+    let mut limits = PowerLimits::<Power>::default();
+    limits.add_limit(limit(4, 10.0, None, LimitStacking::Ignore), 100.0);
+    let outcome = limits.add_limit(limit(4, 50.0, None, LimitStacking::Ignore), 100.0);
+
+    assert_eq!(outcome, LimitStackOutcome::Ignored);
+    assert_eq!(limits.total_reduction(), 10.0);
+}
+
+#[test]
+fn events_apply_stacking_and_lift_stacks() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+
+    let stacking = LimitStacking::Stack { max_stacks: 3 };
+    for _ in 0..3 {
+        app.world_mut().write_message(
+            ApplyLimitEvent::<Power>::points(entity, 9, 10.0, Color::WHITE, None, false)
+                .with_stacking(stacking),
+        );
+    }
+    app.update();
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().max, 70.0);
+
+    app.world_mut()
+        .write_message(LiftLimitEvent::<Power>::one(entity, 9));
+    app.update();
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().max, 80.0);
+
+    app.world_mut()
+        .write_message(LiftLimitEvent::<Power>::all(entity, 9));
+    app.update();
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().max, 100.0);
+}
+
+#[test]
+fn percentage_limits_follow_base_max() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+
+    app.world_mut()
+        .write_message(ApplyLimitEvent::<Power>::percentage(
+            entity,
+            1,
+            20.0,
+            Color::WHITE,
+            None,
+            false,
+        ));
+    app.update();
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().max, 80.0);
+
+    // Level up raises base_max by the default bonus of 5
+    app.world_mut()
+        .write_message(GainExperienceEvent::<Power>::new(entity, 100.0));
+    app.update();
+    let power_bar = app.world().get::<PowerBar>(entity).unwrap();
+    assert_eq!(power_bar.base_max, 105.0);
+    assert_eq!(power_bar.max, 84.0);
+
+    // Direct edits to base_max are picked up as well
+    app.world_mut()
+        .get_mut::<PowerBar>(entity)
+        .unwrap()
+        .base_max = 200.0;
+    app.update();
+    let power_bar = app.world().get::<PowerBar>(entity).unwrap();
+    assert_eq!(power_bar.max, 160.0);
+    let limits = app.world().get::<PowerLimits>(entity).unwrap();
+    assert_eq!(limits.total_reduction(), 40.0);
+}
+
+#[test]
+fn lifecycle_events_are_sent() {
+    let mut app = app();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(
+        std::time::Duration::from_millis(500),
+    ));
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+
+    app.world_mut()
+        .write_message(ApplyLimitEvent::<Power>::points(
+            entity,
+            1,
+            10.0,
+            Color::WHITE,
+            Some(1.0),
+            false,
+        ));
+    app.world_mut()
+        .write_message(ApplyLimitEvent::<Power>::points(
+            entity,
+            2,
+            10.0,
+            Color::WHITE,
+            None,
+            false,
+        ));
+    app.update();
+    let applied = drain::<LimitAppliedEvent>(&mut app);
+    assert_eq!(applied.len(), 2);
+    assert_eq!(applied[0].entity, entity);
+    assert_eq!(applied[0].limit_type, LimitType::Points(10.0));
+
+    app.world_mut().write_message(
+        ApplyLimitEvent::<Power>::points(entity, 1, 10.0, Color::WHITE, Some(1.0), false)
+            .with_stacking(LimitStacking::RefreshDuration),
+    );
+    app.update();
+    assert_eq!(drain::<LimitRefreshedEvent>(&mut app).len(), 1);
+
+    app.world_mut()
+        .write_message(LiftLimitEvent::<Power>::all(entity, 2));
+    app.update();
+    let lifted = drain::<LimitLiftedEvent>(&mut app);
+    assert_eq!(lifted.len(), 1);
+    assert_eq!(lifted[0].id, 2);
+
+    let mut expired = Vec::new();
+    for _ in 0..4 {
+        app.update();
+        expired.extend(drain::<LimitExpiredEvent>(&mut app));
+    }
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].id, 1);
+}
@@ -0,0 +1,187 @@
+mod common;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_power::prelude::*;
+use common::{app_with, drain};
+
+#[derive(Reflect, Default, Clone, Copy, Debug)]
+struct Heat;
+
+impl PowerResource for Heat {
+    const DIRECTION: PowerDirection = PowerDirection::Accumulating;
+}
+
+fn app() -> App {
+    app_with(PowerSystemPlugin::<Heat>::default())
+}
+
+/// Spawn a heat bar that cools by 10 per update from the second update after a spend
+fn spawn(app: &mut App) -> Entity {
+    let entity = app
+        .world_mut()
+        .spawn(PowerBundle::<Heat>::custom(100.0, 0.5, 40.0, 40.0))
+        .id();
+    app.update();
+    entity
+}
+
+fn heat(app: &App, entity: Entity) -> &PowerBar<Heat> {
+    app.world().get::<PowerBar<Heat>>(entity).unwrap()
+}
+
+#[test]
+fn spending_builds_up_and_regeneration_decays() {
+    let mut app = app();
+    let entity = spawn(&mut app);
+    assert_eq!(heat(&app, entity).current, 0.0);
+    assert!(!heat(&app, entity).is_knocked_out);
+
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Heat>::new(entity, 30.0));
+    app.update();
+    assert_eq!(heat(&app, entity).current, 30.0);
+    assert_eq!(heat(&app, entity).available(), 70.0);
+
+    app.update();
+    assert_eq!(heat(&app, entity).current, 20.0);
+
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(heat(&app, entity).current, 0.0);
+    assert!(!heat(&app, entity).is_knocked_out);
+    assert!(drain::<KnockedOutEvent<Heat>>(&mut app).is_empty());
+}
+
+#[test]
+fn full_bar_overloads_until_it_has_cooled_down() {
+    let mut app = app();
+    let entity = spawn(&mut app);
+
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Heat>::new(entity, 80.0));
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Heat>::new(entity, 30.0));
+    app.update();
+    assert_eq!(heat(&app, entity).current, 80.0);
+    let failed = drain::<SpendFailedEvent<Heat>>(&mut app);
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].reason, FailureReason::InsufficientPower);
+
+    // Damage-style drains build the bar up, like hits building rage
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Heat>::build_up(entity, 50.0));
+    app.update();
+    assert_eq!(heat(&app, entity).current, 100.0);
+    assert!(heat(&app, entity).is_overloaded);
+    assert_eq!(drain::<OverloadedEvent<Heat>>(&mut app).len(), 1);
+    assert!(drain::<KnockedOutEvent<Heat>>(&mut app).is_empty());
+
+    let result = app
+        .world_mut()
+        .run_system_once(move |mut heat: PowerSystem<Heat>| heat.entity(entity).try_spend(1.0))
+        .unwrap();
+    assert_eq!(result, Err(PowerError::Overloaded));
+
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(heat(&app, entity).current, 0.0);
+    assert!(!heat(&app, entity).is_overloaded);
+    assert_eq!(drain::<OverloadClearedEvent<Heat>>(&mut app).len(), 1);
+
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Heat>::new(entity, 10.0));
+    app.update();
+    assert_eq!(heat(&app, entity).current, 10.0);
+}
+
+#[test]
+fn overloaded_bars_cool_down_past_the_regen_ceiling() {
+    let mut app = app();
+    let entity = spawn(&mut app);
+    app.world_mut()
+        .get_mut::<PowerRegeneration<Heat>>(entity)
+        .unwrap()
+        .ceiling = Some(RegenCeiling::Percentage(50.0));
+
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Heat>::build_up(entity, 100.0));
+    app.update();
+    assert!(heat(&app, entity).is_overloaded);
+
+    for _ in 0..12 {
+        app.update();
+    }
+    assert_eq!(heat(&app, entity).current, 0.0);
+    assert!(!heat(&app, entity).is_overloaded);
+
+    // Once cooled down the ceiling caps the decay again
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Heat>::new(entity, 80.0));
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(heat(&app, entity).current, 50.0);
+}
+
+#[test]
+fn limits_that_leave_no_headroom_overload() {
+    let mut app = app();
+    let entity = spawn(&mut app);
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Heat>::new(entity, 80.0));
+    app.update();
+
+    let limit = ApplyLimitEvent::<Heat>::points(entity, 1, 30.0, Color::WHITE, None, false);
+    let checked = limit.clone();
+    let result = app
+        .world_mut()
+        .run_system_once(move |mut heat: PowerSystem<Heat>| {
+            heat.entity(entity).try_apply_limit(checked.clone())
+        })
+        .unwrap();
+    assert_eq!(result, Err(PowerError::WouldOverload));
+    assert_eq!(
+        drain::<LimitRejectedEvent<Heat>>(&mut app)[0].reason,
+        FailureReason::WouldOverload
+    );
+
+    app.world_mut().write_message(limit);
+    app.update();
+    let bar = heat(&app, entity);
+    assert_eq!((bar.current, bar.max), (70.0, 70.0));
+    assert!(bar.is_overloaded);
+    assert_eq!(drain::<OverloadedEvent<Heat>>(&mut app).len(), 1);
+}
+
+#[test]
+fn change_events_are_signed_by_the_resource() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn(PowerBundle::<Heat>::custom(100.0, 100.0, 0.0, 0.0))
+        .id();
+    app.update();
+
+    // A negative amount raises the bar, a positive one lowers it
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Heat>::drain(entity, 40.0));
+    app.update();
+    assert_eq!(heat(&app, entity).current, 40.0);
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Heat>::safe(entity, 15.0));
+    app.update();
+    assert_eq!(heat(&app, entity).current, 25.0);
+
+    // The named constructors take plain amounts
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Heat>::build_up(entity, 30.0));
+    app.update();
+    assert_eq!(heat(&app, entity).current, 55.0);
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Heat>::vent(entity, 20.0));
+    app.update();
+    assert_eq!(heat(&app, entity).current, 35.0);
+}
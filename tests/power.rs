@@ -0,0 +1,334 @@
+mod common;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_power::prelude::*;
+use common::{app, drain};
+
+#[test]
+fn power_changes_report_old_new_and_cause() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+    drain::<PowerChangedEvent>(&mut app);
+
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Power>::new(entity, 30.0));
+    app.update();
+    let changes = drain::<PowerChangedEvent>(&mut app);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].entity, entity);
+    assert_eq!(changes[0].cause, PowerChangeCause::Spend);
+    assert_eq!(
+        (changes[0].old, changes[0].new, changes[0].max),
+        (100.0, 70.0, 100.0)
+    );
+
+    app.world_mut()
+        .write_message(ApplyLimitEvent::<Power>::points(
+            entity,
+            1,
+            50.0,
+            Color::WHITE,
+            None,
+            false,
+        ));
+    app.update();
+    let changes = drain::<PowerChangedEvent>(&mut app);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].cause, PowerChangeCause::LimitClamp);
+    assert_eq!(
+        (changes[0].old, changes[0].new, changes[0].max),
+        (70.0, 50.0, 50.0)
+    );
+
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::safe(entity, -10.0));
+    app.update();
+    let changes = drain::<PowerChangedEvent>(&mut app);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].cause, PowerChangeCause::Change);
+    assert_eq!(changes[0].new, 40.0);
+}
+
+#[test]
+fn rejected_spends_send_no_change() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+    drain::<PowerChangedEvent>(&mut app);
+
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Power>::new(entity, 500.0));
+    app.update();
+    assert!(drain::<PowerChangedEvent>(&mut app).is_empty());
+}
+
+#[test]
+fn rejected_spends_report_a_reason() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Power>::new(entity, 500.0));
+    app.update();
+    let failed = drain::<SpendFailedEvent>(&mut app);
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].reason, FailureReason::InsufficientPower);
+
+    let result = app
+        .world_mut()
+        .run_system_once(|mut power: PowerSystem| power.try_spend(500.0))
+        .unwrap();
+    assert_eq!(result, Err(PowerError::InsufficientPower));
+    let failed = drain::<SpendFailedEvent>(&mut app);
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].amount, 500.0);
+}
+
+/// Resolve the primary power entity of the app's world
+fn primary(app: &mut App) -> Result<Entity, PowerError> {
+    app.world_mut()
+        .run_system_once(|power: PowerSystem| power.primary_entity())
+        .unwrap()
+}
+
+#[test]
+fn single_power_bar_is_the_primary_entity() {
+    let mut app = app();
+    assert_eq!(primary(&mut app), Err(PowerError::NoPrimaryEntity));
+
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    assert_eq!(primary(&mut app), Ok(entity));
+
+    // A second unmarked bar leaves no way to choose
+    app.world_mut().spawn(PowerBundle::<Power>::new());
+    assert_eq!(primary(&mut app), Err(PowerError::NoPrimaryEntity));
+    let result = app
+        .world_mut()
+        .run_system_once(|mut power: PowerSystem| power.try_spend(10.0))
+        .unwrap();
+    assert_eq!(result, Err(PowerError::NoPrimaryEntity));
+}
+
+#[test]
+fn marked_entity_is_the_primary_entity() {
+    let mut app = app();
+    app.world_mut().spawn(PowerBundle::<Power>::new());
+    let player = app
+        .world_mut()
+        .spawn((PowerBundle::<Power>::new(), PrimaryPower))
+        .id();
+    app.update();
+    assert_eq!(primary(&mut app), Ok(player));
+
+    app.world_mut()
+        .run_system_once(|mut power: PowerSystem| power.try_spend(30.0))
+        .unwrap()
+        .unwrap();
+    app.update();
+    assert_eq!(app.world().get::<PowerBar>(player).unwrap().current, 70.0);
+
+    // A second marker makes the choice ambiguous instead of picking one
+    app.world_mut()
+        .spawn((PowerBundle::<Power>::new(), PrimaryPower));
+    assert_eq!(primary(&mut app), Err(PowerError::AmbiguousPrimaryEntity));
+    let result = app
+        .world_mut()
+        .run_system_once(|mut power: PowerSystem| power.try_spend(10.0))
+        .unwrap();
+    assert_eq!(result, Err(PowerError::AmbiguousPrimaryEntity));
+}
+
+#[test]
+fn try_limits_reject_knockouts() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    let result = app
+        .world_mut()
+        .run_system_once(|mut power: PowerSystem| {
+            power.try_limit_points(1, 100.0, Color::WHITE, None, false)
+        })
+        .unwrap();
+    assert_eq!(result, Err(PowerError::WouldKnockOut));
+    let rejected = drain::<LimitRejectedEvent>(&mut app);
+    assert_eq!(rejected.len(), 1);
+    assert_eq!(rejected[0].entity, entity);
+    assert_eq!(rejected[0].reason, FailureReason::WouldKnockOut);
+
+    let missing = app.world_mut().spawn_empty().id();
+    let result = app
+        .world_mut()
+        .run_system_once(move |mut power: PowerSystem| power.entity(missing).try_spend(1.0))
+        .unwrap();
+    assert_eq!(result, Err(PowerError::MissingPowerBar(missing)));
+    let failed = drain::<SpendFailedEvent>(&mut app);
+    assert_eq!(failed[0].reason, FailureReason::MissingComponent);
+}
+
+#[test]
+fn drain_changes_knock_out() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    // Safe decreases larger than the remaining power are refused
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::safe(entity, -150.0));
+    app.update();
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().current, 100.0);
+
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::drain(entity, 150.0));
+    app.update();
+    let power_bar = app.world().get::<PowerBar>(entity).unwrap();
+    assert_eq!(power_bar.current, 0.0);
+    assert!(power_bar.is_knocked_out);
+    let knocked_out = drain::<KnockedOutEvent>(&mut app);
+    assert_eq!(knocked_out.len(), 1);
+    assert_eq!(knocked_out[0].entity, entity);
+}
+
+#[test]
+fn spends_in_the_same_frame_see_each_other() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    let results = app
+        .world_mut()
+        .run_system_once(|mut power: PowerSystem| (power.try_spend(60.0), power.try_spend(60.0)))
+        .unwrap();
+    assert_eq!(results, (Ok(()), Err(PowerError::InsufficientPower)));
+
+    app.update();
+    let power_bar = app.world().get::<PowerBar>(entity).unwrap();
+    assert_eq!((power_bar.current, power_bar.reserved), (40.0, 0.0));
+}
+
+#[test]
+fn spends_work_without_regeneration() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBar::<Power>::new(100.0)).id();
+    app.update();
+
+    for _ in 0..3 {
+        let result = app
+            .world_mut()
+            .run_system_once(move |mut power: PowerSystem| power.entity(entity).try_spend(20.0))
+            .unwrap();
+        assert_eq!(result, Ok(()));
+        app.update();
+    }
+    let power_bar = app.world().get::<PowerBar>(entity).unwrap();
+    assert_eq!((power_bar.current, power_bar.reserved), (40.0, 0.0));
+    assert!(drain::<SpendFailedEvent>(&mut app).is_empty());
+}
+
+#[test]
+fn reservations_hold_power_until_cancelled() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    let (held, released) = app
+        .world_mut()
+        .run_system_once(move |mut power: PowerSystem| {
+            let reservation = power.reserve(80.0).unwrap();
+            assert_eq!(reservation.entity(), entity);
+            let held = power.try_spend(30.0);
+            power.cancel(reservation);
+            (held, power.try_spend(30.0))
+        })
+        .unwrap();
+    assert_eq!(held, Err(PowerError::InsufficientPower));
+    assert_eq!(released, Ok(()));
+
+    app.update();
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().current, 70.0);
+}
+
+#[test]
+fn dropped_reservations_hold_power_until_cleared() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    app.world_mut()
+        .run_system_once(|mut power: PowerSystem| {
+            drop(power.reserve(80.0).unwrap());
+        })
+        .unwrap();
+    app.update();
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().reserved, 80.0);
+
+    let (leaked, cleared) = app
+        .world_mut()
+        .run_system_once(|mut power: PowerSystem| {
+            let leaked = power.try_spend(30.0);
+            power.clear_reservations().unwrap();
+            (leaked, power.try_spend(30.0))
+        })
+        .unwrap();
+    assert_eq!(leaked, Err(PowerError::InsufficientPower));
+    assert_eq!(cleared, Ok(()));
+
+    app.update();
+    let power_bar = app.world().get::<PowerBar>(entity).unwrap();
+    assert_eq!((power_bar.current, power_bar.reserved), (70.0, 0.0));
+}
+
+#[test]
+fn transactions_spend_everything_or_nothing() {
+    let mut app = app();
+    let caster = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    let ally = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    let result = app
+        .world_mut()
+        .run_system_once(move |mut power: PowerSystem| {
+            power.try_spend_all(&[(caster, 50.0), (ally, 20.0), (caster, 60.0)])
+        })
+        .unwrap();
+    assert_eq!(result, Err(PowerError::InsufficientPower));
+    app.update();
+    for entity in [caster, ally] {
+        let power_bar = app.world().get::<PowerBar>(entity).unwrap();
+        assert_eq!((power_bar.current, power_bar.reserved), (100.0, 0.0));
+    }
+
+    let result = app
+        .world_mut()
+        .run_system_once(move |mut power: PowerSystem| {
+            power.try_spend_all(&[(caster, 50.0), (ally, 20.0), (caster, 30.0)])
+        })
+        .unwrap();
+    assert_eq!(result, Ok(()));
+    app.update();
+    assert_eq!(app.world().get::<PowerBar>(caster).unwrap().current, 20.0);
+    assert_eq!(app.world().get::<PowerBar>(ally).unwrap().current, 80.0);
+}
+
+#[test]
+fn core_plugin_runs_headless() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins)
+        .add_plugins(PowerCorePlugin::<Power>::default());
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Power>::new(entity, 30.0));
+    app.update();
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().current, 70.0);
+
+    #[cfg(feature = "ui")]
+    {
+        let world = app.world_mut();
+        assert_eq!(world.query::<&PowerBarUI>().iter(world).count(), 0);
+    }
+}
@@ -0,0 +1,140 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::scene::{ron, serde::SceneDeserializer, DynamicSceneBuilder};
+use bevy_power::prelude::*;
+use common::{app, app_with};
+
+fn spawn_player(world: &mut World) -> Entity {
+    let mut limits = PowerLimits::<Power>::default();
+    limits.add_limit(
+        PowerLimit::new(
+            7,
+            LimitType::Percentage(25.0),
+            Color::WHITE,
+            Some(4.0),
+            true,
+        ),
+        120.0,
+    );
+    world
+        .spawn((
+            PowerBar::<Power> {
+                current: 60.0,
+                max: 90.0,
+                base_max: 120.0,
+                is_knocked_out: false,
+                ..Default::default()
+            },
+            PowerLevel::<Power> {
+                level: 3,
+                ..Default::default()
+            },
+            PowerRegeneration::<Power>::default(),
+            limits,
+            PrimaryPower,
+        ))
+        .id()
+}
+
+#[test]
+fn types_are_registered() {
+    let app = app();
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    assert!(registry.get(std::any::TypeId::of::<PowerBar>()).is_some());
+    assert!(registry
+        .get(std::any::TypeId::of::<PowerLimits>())
+        .is_some());
+    assert!(registry.get(std::any::TypeId::of::<LimitType>()).is_some());
+    assert!(registry
+        .get(std::any::TypeId::of::<LevelUpEvent>())
+        .is_some());
+}
+
+#[test]
+fn dynamic_scene_round_trip() {
+    let mut source = app();
+    let player = spawn_player(source.world_mut());
+
+    let scene = DynamicSceneBuilder::from_world(source.world())
+        .extract_entity(player)
+        .build();
+
+    let mut target = app();
+    let mut entity_map = Default::default();
+    scene
+        .write_to_world(target.world_mut(), &mut entity_map)
+        .unwrap();
+
+    let loaded = entity_map[&player];
+    let world = target.world();
+    assert_eq!(world.get::<PowerBar>(loaded).unwrap().current, 60.0);
+    assert_eq!(world.get::<PowerLevel>(loaded).unwrap().level, 3);
+    assert!(world.get::<PrimaryPower>(loaded).is_some());
+    let limits = world.get::<PowerLimits>(loaded).unwrap();
+    assert_eq!(limits.limits[0].limit_type, LimitType::Percentage(25.0));
+    assert_eq!(limits.limits[0].power_value, 30.0);
+}
+
+#[test]
+fn scene_ron_round_trip() {
+    let mut source = app();
+    let player = spawn_player(source.world_mut());
+    source
+        .world_mut()
+        .entity_mut(player)
+        .insert(LevelingCurves {
+            experience_to_next: LevelingCurve::Linear {
+                base: 40.0,
+                per_level: 10.0,
+            },
+            power_bonus: LevelingCurve::custom(|level| level as f32),
+        });
+
+    let scene = DynamicSceneBuilder::from_world(source.world())
+        .allow_resource::<LevelingCurves>()
+        .extract_entity(player)
+        .extract_resources()
+        .build();
+    let text = {
+        let registry = source.world().resource::<AppTypeRegistry>().read();
+        scene.serialize(&registry).unwrap()
+    };
+
+    let mut target = app_with(PowerSystemPlugin::<Power> {
+        leveling_curves: Some(LevelingCurves {
+            experience_to_next: LevelingCurve::Table(vec![1.0]),
+            power_bonus: LevelingCurve::Table(vec![0.0]),
+        }),
+        ..default()
+    });
+    let scene = {
+        let registry = target.world().resource::<AppTypeRegistry>().read();
+        ron::Options::default()
+            .from_str_seed(
+                &text,
+                SceneDeserializer {
+                    type_registry: &registry,
+                },
+            )
+            .unwrap()
+    };
+    let mut entity_map = Default::default();
+    scene
+        .write_to_world(target.world_mut(), &mut entity_map)
+        .unwrap();
+
+    let world = target.world();
+    let loaded = entity_map[&player];
+    assert_eq!(world.get::<PowerBar>(loaded).unwrap().base_max, 120.0);
+    assert_eq!(world.get::<PowerLimits>(loaded).unwrap().limits[0].id, 7);
+
+    // Built-in curves load back as saved, custom ones without their closure
+    let curves = world.get::<LevelingCurves>(loaded).unwrap();
+    assert_eq!(curves.experience_to_next.evaluate(3), 60.0);
+    assert_eq!(curves.power_bonus.evaluate(3), 0.0);
+
+    let defaults = world.resource::<LevelingCurves>();
+    assert_eq!(defaults.experience_to_next.evaluate(2), 150.0);
+    assert_eq!(defaults.power_bonus.evaluate(50), 8.0);
+}
@@ -0,0 +1,198 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_power::prelude::*;
+use common::app;
+
+fn regen(curve: RegenCurve) -> PowerRegeneration {
+    PowerRegeneration::<Power> {
+        regen_delay: 1.0,
+        base_rate: 5.0,
+        max_rate: 20.0,
+        ramp_speed: 2.0,
+        curve,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn regeneration_starts_at_base_rate() {
+    let mut regen = regen(RegenCurve::Linear);
+    regen.update(0.5, 100.0);
+    assert!(!regen.is_active);
+    assert_eq!(regen.get_regen_amount(0.5), 0.0);
+
+    regen.update(0.5, 100.0);
+    assert!(regen.is_active);
+    assert_eq!(regen.current_rate, 5.0);
+
+    regen.update(2.0, 100.0);
+    assert_eq!(regen.current_rate, 9.0);
+    regen.update(100.0, 100.0);
+    assert_eq!(regen.current_rate, 20.0);
+}
+
+#[test]
+fn curves_shape_the_rate() {
+    let constant = regen(RegenCurve::Constant);
+    assert_eq!(constant.rate_at(0.0, 100.0), 5.0);
+    assert_eq!(constant.rate_at(60.0, 100.0), 5.0);
+
+    let ease_in = regen(RegenCurve::EaseIn { duration: 4.0 });
+    assert_eq!(ease_in.rate_at(0.0, 100.0), 5.0);
+    assert!(ease_in.rate_at(2.0, 100.0) < 6.0);
+    assert_eq!(ease_in.rate_at(4.0, 100.0), 20.0);
+
+    let percent = regen(RegenCurve::PercentOfMax(10.0));
+    assert_eq!(percent.rate_at(0.0, 200.0), 20.0);
+
+    let sampled = regen(RegenCurve::Sampled {
+        step: 1.0,
+        rates: vec![2.0, 4.0, 10.0],
+    });
+    assert_eq!(sampled.rate_at(0.0, 100.0), 2.0);
+    assert_eq!(sampled.rate_at(1.5, 100.0), 7.0);
+    assert_eq!(sampled.rate_at(30.0, 100.0), 10.0);
+}
+
+#[test]
+fn regenerate_power_uses_the_curve() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            PowerBar::<Power> {
+                current: 50.0,
+                ..Default::default()
+            },
+            PowerRegeneration::<Power> {
+                regen_delay: 0.0,
+                curve: RegenCurve::PercentOfMax(8.0),
+                ..Default::default()
+            },
+        ))
+        .id();
+    app.update();
+    let before = app.world().get::<PowerBar>(entity).unwrap().current;
+    app.update();
+    app.update();
+
+    let after = app.world().get::<PowerBar>(entity).unwrap().current;
+    assert_eq!(after - before, 4.0);
+}
+
+#[test]
+fn modifiers_add_then_multiply() {
+    let mut modifiers = RegenModifiers::<Power>::default();
+    modifiers.add(RegenModifier::new(
+        1,
+        RegenModifierKind::Multiply(1.5),
+        None,
+    ));
+    modifiers.add(RegenModifier::new(
+        2,
+        RegenModifierKind::Add(5.0),
+        Some(2.0),
+    ));
+    assert_eq!(modifiers.apply(10.0), 22.5);
+
+    let replaced = modifiers.add(RegenModifier::new(
+        1,
+        RegenModifierKind::Multiply(2.0),
+        None,
+    ));
+    assert!(replaced.is_some());
+    assert_eq!(modifiers.apply(10.0), 30.0);
+
+    modifiers.add(RegenModifier::new(3, RegenModifierKind::Add(-100.0), None));
+    assert_eq!(modifiers.apply(10.0), 0.0);
+    modifiers.remove(3);
+
+    modifiers.add(RegenModifier::new(4, RegenModifierKind::Block, None));
+    assert!(modifiers.is_blocked());
+    assert_eq!(modifiers.apply(10.0), 0.0);
+
+    let expired = modifiers.update_timers(2.0);
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].id, 2);
+}
+
+#[test]
+fn blocked_regeneration_resumes_when_the_modifier_expires() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            PowerBar::<Power> {
+                current: 50.0,
+                ..Default::default()
+            },
+            PowerRegeneration::<Power> {
+                regen_delay: 0.0,
+                curve: RegenCurve::Constant,
+                ..Default::default()
+            },
+        ))
+        .id();
+    app.world_mut()
+        .write_message(ApplyRegenModifierEvent::<Power>::new(
+            entity,
+            RegenModifier::new(1, RegenModifierKind::Block, Some(0.5)),
+        ));
+    app.update();
+    app.update();
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().current, 50.0);
+
+    app.update();
+    let expired = app
+        .world_mut()
+        .resource_mut::<Messages<RegenModifierExpiredEvent>>()
+        .drain()
+        .collect::<Vec<_>>();
+    assert_eq!(expired.len(), 1);
+    assert!(app.world().get::<PowerBar>(entity).unwrap().current > 50.0);
+}
+
+#[test]
+fn regeneration_stops_at_the_ceiling() {
+    let regen = PowerRegeneration::<Power> {
+        ceiling: Some(RegenCeiling::Percentage(50.0)),
+        ..Default::default()
+    };
+    assert_eq!(regen.ceiling_for(200.0), 100.0);
+    let points = PowerRegeneration::<Power> {
+        ceiling: Some(RegenCeiling::Points(500.0)),
+        ..Default::default()
+    };
+    assert_eq!(points.ceiling_for(200.0), 200.0);
+
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            PowerBar::<Power> {
+                current: 55.0,
+                ..Default::default()
+            },
+            PowerRegeneration::<Power> {
+                regen_delay: 0.0,
+                base_rate: 20.0,
+                curve: RegenCurve::Constant,
+                ceiling: Some(RegenCeiling::Percentage(60.0)),
+                ..Default::default()
+            },
+        ))
+        .id();
+    for _ in 0..8 {
+        app.update();
+    }
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().current, 60.0);
+
+    // Pickups still fill past the ceiling, regeneration leaves it alone
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::safe(entity, 30.0));
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(app.world().get::<PowerBar>(entity).unwrap().current, 90.0);
+}
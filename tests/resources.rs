@@ -0,0 +1,92 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_power::prelude::*;
+use common::app_with;
+
+#[derive(Reflect, Default, Clone, Copy, Debug)]
+struct Mana;
+
+impl PowerResource for Mana {}
+
+fn app() -> App {
+    app_with((
+        PowerSystemPlugin::<Power>::default(),
+        PowerSystemPlugin::<Mana>::default(),
+    ))
+}
+
+#[test]
+fn resources_on_one_entity_are_independent() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((
+            PowerBundle::<Power>::with_max_power(100.0),
+            PowerBundle::<Mana>::with_max_power(50.0),
+        ))
+        .id();
+    app.update();
+
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Mana>::new(entity, 20.0));
+    app.world_mut()
+        .write_message(ApplyLimitEvent::<Power>::points(
+            entity,
+            1,
+            30.0,
+            Color::WHITE,
+            None,
+            false,
+        ));
+    app.update();
+
+    let power = app.world().get::<PowerBar>(entity).unwrap();
+    assert_eq!((power.current, power.max), (70.0, 70.0));
+    let mana = app.world().get::<PowerBar<Mana>>(entity).unwrap();
+    assert_eq!((mana.current, mana.max), (30.0, 50.0));
+
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Mana>::drain(entity, 100.0));
+    app.update();
+    assert!(
+        app.world()
+            .get::<PowerBar<Mana>>(entity)
+            .unwrap()
+            .is_knocked_out
+    );
+    assert!(!app.world().get::<PowerBar>(entity).unwrap().is_knocked_out);
+}
+
+#[cfg(feature = "ui")]
+#[test]
+fn every_resource_gets_its_own_bar() {
+    let mut app = app();
+    let entity = app
+        .world_mut()
+        .spawn((PowerBundle::<Power>::new(), PowerBundle::<Mana>::new()))
+        .id();
+    app.update();
+
+    let world = app.world_mut();
+    let power_bar = world
+        .query::<(&PowerBarUI, &Node)>()
+        .single(world)
+        .map(|(ui, node)| (ui.target, node.top))
+        .unwrap();
+    let mana_bar = world
+        .query::<(&PowerBarUI<Mana>, &Node)>()
+        .single(world)
+        .map(|(ui, node)| (ui.target, node.top))
+        .unwrap();
+    assert_eq!(power_bar.0, entity);
+    assert_eq!(mana_bar.0, entity);
+    assert_ne!(power_bar.1, mana_bar.1);
+
+    // Removing one resource frees only its bar
+    world.entity_mut(entity).remove::<PowerBar<Mana>>();
+    app.update();
+    let world = app.world_mut();
+    assert_eq!(world.query::<&PowerBarUI<Mana>>().iter(world).count(), 0);
+    assert_eq!(world.query::<&PowerBarUI>().iter(world).count(), 1);
+}
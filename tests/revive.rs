@@ -0,0 +1,258 @@
+mod common;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_power::prelude::*;
+use common::{app, drain};
+
+/// Spawn a reviver and a knocked out teammate
+fn setup(app: &mut App, policy: KnockoutPolicy) -> (Entity, Entity) {
+    let player = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    let teammate = app
+        .world_mut()
+        .spawn((PowerBundle::<Power>::new(), policy))
+        .id();
+    app.update();
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::drain(teammate, 100.0));
+    app.update();
+    assert!(
+        app.world()
+            .get::<PowerBar>(teammate)
+            .unwrap()
+            .is_knocked_out
+    );
+    (player, teammate)
+}
+
+#[test]
+fn channelled_revive_costs_the_reviver() {
+    let mut app = app();
+    let (player, teammate) = setup(&mut app, KnockoutPolicy::Manual);
+
+    app.world_mut()
+        .run_system_once(move |mut power: PowerSystem| {
+            power.entity(player).start_revive(teammate, 1.0, 40.0, 20.0)
+        })
+        .unwrap()
+        .unwrap();
+    app.update();
+    assert_eq!(drain::<ReviveStartedEvent>(&mut app).len(), 1);
+    assert_eq!(app.world().get::<PowerBar>(player).unwrap().reserved, 20.0);
+
+    app.update();
+    app.update();
+    let attempt = app.world().get::<ReviveAttempt>(teammate).unwrap();
+    assert_eq!((attempt.reviver, attempt.fraction()), (player, 0.75));
+
+    app.update();
+    app.update();
+    let revived = app.world().get::<PowerBar>(teammate).unwrap();
+    assert!(!revived.is_knocked_out);
+    assert_eq!(revived.current, 40.0);
+    let reviver = app.world().get::<PowerBar>(player).unwrap();
+    assert_eq!((reviver.current, reviver.reserved), (80.0, 0.0));
+    assert!(app.world().get::<ReviveAttempt>(teammate).is_none());
+}
+
+#[test]
+fn revive_cost_is_paid_during_a_grace_window() {
+    let mut app = app();
+    let (player, teammate) = setup(&mut app, KnockoutPolicy::Manual);
+
+    app.world_mut()
+        .write_message(StartReviveEvent::<Power>::new(
+            player, teammate, 0.5, 40.0, 20.0,
+        ));
+    app.update();
+    let grace = GraceWindow::<Power>::new(&GracePolicy {
+        duration: 5.0,
+        blocks_spends: true,
+        ..Default::default()
+    });
+    app.world_mut().entity_mut(player).insert(grace);
+
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(
+        !app.world()
+            .get::<PowerBar>(teammate)
+            .unwrap()
+            .is_knocked_out
+    );
+    let reviver = app.world().get::<PowerBar>(player).unwrap();
+    assert_eq!((reviver.current, reviver.reserved), (80.0, 0.0));
+    assert!(drain::<SpendFailedEvent>(&mut app).is_empty());
+}
+
+#[test]
+fn revives_the_reviver_can_no_longer_pay_are_cancelled() {
+    let mut app = app();
+    let (player, teammate) = setup(&mut app, KnockoutPolicy::Manual);
+
+    app.world_mut()
+        .write_message(StartReviveEvent::<Power>::new(
+            player, teammate, 1.0, 40.0, 20.0,
+        ));
+    app.update();
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::drain(player, 90.0));
+    for _ in 0..4 {
+        app.update();
+    }
+
+    let cancelled = drain::<ReviveCancelledEvent>(&mut app);
+    assert_eq!(cancelled.len(), 1);
+    assert_eq!(cancelled[0].reason, ReviveCancelReason::InsufficientPower);
+    let reviver = app.world().get::<PowerBar>(player).unwrap();
+    assert!(!reviver.is_knocked_out);
+    assert_eq!(reviver.reserved, 0.0);
+    assert!(
+        app.world()
+            .get::<PowerBar>(teammate)
+            .unwrap()
+            .is_knocked_out
+    );
+}
+
+#[test]
+fn restarted_revives_reuse_the_old_reservation() {
+    let mut app = app();
+    let (player, teammate) = setup(&mut app, KnockoutPolicy::Manual);
+
+    app.world_mut()
+        .write_message(StartReviveEvent::<Power>::new(
+            player, teammate, 2.0, 40.0, 60.0,
+        ));
+    app.update();
+    app.world_mut()
+        .write_message(StartReviveEvent::<Power>::new(
+            player, teammate, 1.0, 40.0, 60.0,
+        ));
+    app.update();
+
+    let cancelled = drain::<ReviveCancelledEvent>(&mut app);
+    assert_eq!(cancelled.len(), 1);
+    assert_eq!(cancelled[0].reason, ReviveCancelReason::Replaced);
+    assert_eq!(drain::<ReviveStartedEvent>(&mut app).len(), 2);
+    assert_eq!(app.world().get::<PowerBar>(player).unwrap().reserved, 60.0);
+    assert_eq!(
+        app.world().get::<ReviveAttempt>(teammate).unwrap().duration,
+        1.0
+    );
+}
+
+#[test]
+fn cancelled_revives_release_the_cost() {
+    let mut app = app();
+    let (player, teammate) = setup(&mut app, KnockoutPolicy::Manual);
+
+    app.world_mut()
+        .write_message(StartReviveEvent::<Power>::new(
+            player, teammate, 2.0, 40.0, 20.0,
+        ));
+    app.update();
+    app.world_mut()
+        .write_message(CancelReviveEvent::<Power>::new(teammate));
+    app.update();
+
+    let cancelled = drain::<ReviveCancelledEvent>(&mut app);
+    assert_eq!(cancelled.len(), 1);
+    assert_eq!(cancelled[0].reason, ReviveCancelReason::Cancelled);
+    assert_eq!(app.world().get::<PowerBar>(player).unwrap().reserved, 0.0);
+    assert!(
+        app.world()
+            .get::<PowerBar>(teammate)
+            .unwrap()
+            .is_knocked_out
+    );
+    assert!(app.world().get::<ReviveAttempt>(teammate).is_none());
+}
+
+#[test]
+fn despawning_the_target_cancels_the_revive() {
+    let mut app = app();
+    let (player, teammate) = setup(&mut app, KnockoutPolicy::Manual);
+
+    app.world_mut()
+        .write_message(StartReviveEvent::<Power>::new(
+            player, teammate, 2.0, 40.0, 20.0,
+        ));
+    app.update();
+    drain::<ReviveCancelledEvent>(&mut app);
+    app.world_mut().despawn(teammate);
+    app.update();
+
+    let cancelled = drain::<ReviveCancelledEvent>(&mut app);
+    assert_eq!(cancelled.len(), 1);
+    assert_eq!(cancelled[0].reason, ReviveCancelReason::Removed);
+    assert_eq!(app.world().get::<PowerBar>(player).unwrap().reserved, 0.0);
+}
+
+#[test]
+fn ended_revives_are_not_cancelled_again() {
+    let mut app = app();
+    let (player, teammate) = setup(&mut app, KnockoutPolicy::Manual);
+
+    app.world_mut()
+        .write_message(StartReviveEvent::<Power>::new(
+            player, teammate, 0.25, 40.0, 20.0,
+        ));
+    app.update();
+    app.update();
+    assert_eq!(drain::<ReviveEvent>(&mut app).len(), 1);
+    assert!(drain::<ReviveCancelledEvent>(&mut app).is_empty());
+    assert_eq!(app.world().get::<PowerBar>(player).unwrap().current, 80.0);
+}
+
+#[test]
+fn revives_are_rejected_with_a_reason() {
+    let mut app = app();
+    let (player, teammate) = setup(&mut app, KnockoutPolicy::Manual);
+
+    app.world_mut()
+        .write_message(StartReviveEvent::<Power>::new(
+            teammate, player, 1.0, 40.0, 0.0,
+        ));
+    app.world_mut()
+        .write_message(StartReviveEvent::<Power>::new(
+            player, teammate, 1.0, 40.0, 150.0,
+        ));
+    app.update();
+
+    let reasons: Vec<_> = drain::<ReviveCancelledEvent>(&mut app)
+        .into_iter()
+        .map(|event| event.reason)
+        .collect();
+    assert_eq!(
+        reasons,
+        [
+            ReviveCancelReason::TargetNotKnockedOut,
+            ReviveCancelReason::InsufficientPower
+        ]
+    );
+    assert!(drain::<ReviveStartedEvent>(&mut app).is_empty());
+}
+
+#[test]
+fn bleed_out_pauses_while_being_revived() {
+    let mut app = app();
+    let (player, teammate) = setup(&mut app, KnockoutPolicy::Downed { bleed_out: 0.5 });
+
+    app.world_mut()
+        .write_message(StartReviveEvent::<Power>::new(
+            player, teammate, 1.5, 40.0, 0.0,
+        ));
+    for _ in 0..6 {
+        app.update();
+        assert!(drain::<KnockedOutEvent>(&mut app).is_empty());
+    }
+    app.update();
+    assert!(
+        !app.world()
+            .get::<PowerBar>(teammate)
+            .unwrap()
+            .is_knocked_out
+    );
+}
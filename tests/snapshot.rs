@@ -0,0 +1,320 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_power::prelude::*;
+use common::app;
+
+fn spawn_damaged(world: &mut World) -> Entity {
+    let mut limits = PowerLimits::<Power>::default();
+    limits.add_limit(
+        PowerLimit::new(1, LimitType::Points(20.0), Color::WHITE, Some(5.0), false),
+        150.0,
+    );
+    limits.add_limit(
+        PowerLimit::new(2, LimitType::Percentage(10.0), Color::BLACK, None, true),
+        150.0,
+    );
+    if let Some(timer) = limits.limits[0].duration.as_mut() {
+        timer.tick(std::time::Duration::from_secs_f32(1.5));
+    }
+
+    world
+        .spawn((
+            PowerBar::<Power> {
+                current: 42.0,
+                max: 115.0,
+                base_max: 150.0,
+                is_knocked_out: false,
+                ..Default::default()
+            },
+            PowerLevel::<Power> {
+                level: 4,
+                experience: 12.5,
+                experience_to_next: 337.5,
+                ..Default::default()
+            },
+            PowerRegeneration::<Power> {
+                time_since_spend: 3.0,
+                current_rate: 7.5,
+                is_active: true,
+                ..Default::default()
+            },
+            limits,
+        ))
+        .id()
+}
+
+fn assert_same_state(world: &World, a: Entity, b: Entity) {
+    let (bar_a, bar_b) = (
+        world.get::<PowerBar>(a).unwrap(),
+        world.get::<PowerBar>(b).unwrap(),
+    );
+    assert_eq!(bar_a.current, bar_b.current);
+    assert_eq!(bar_a.max, bar_b.max);
+    assert_eq!(bar_a.base_max, bar_b.base_max);
+    assert_eq!(bar_a.is_knocked_out, bar_b.is_knocked_out);
+
+    let (lvl_a, lvl_b) = (
+        world.get::<PowerLevel>(a).unwrap(),
+        world.get::<PowerLevel>(b).unwrap(),
+    );
+    assert_eq!(lvl_a.level, lvl_b.level);
+    assert_eq!(lvl_a.experience, lvl_b.experience);
+    assert_eq!(lvl_a.experience_to_next, lvl_b.experience_to_next);
+
+    let (regen_a, regen_b) = (
+        world.get::<PowerRegeneration>(a).unwrap(),
+        world.get::<PowerRegeneration>(b).unwrap(),
+    );
+    assert_eq!(regen_a.time_since_spend, regen_b.time_since_spend);
+    assert_eq!(regen_a.current_rate, regen_b.current_rate);
+    assert_eq!(regen_a.is_active, regen_b.is_active);
+
+    let (limits_a, limits_b) = (
+        world.get::<PowerLimits>(a).unwrap(),
+        world.get::<PowerLimits>(b).unwrap(),
+    );
+    assert_eq!(limits_a.limits.len(), limits_b.limits.len());
+    for (la, lb) in limits_a.limits.iter().zip(limits_b.limits.iter()) {
+        assert_eq!(la.id, lb.id);
+        assert_eq!(la.limit_type, lb.limit_type);
+        assert_eq!(la.power_value, lb.power_value);
+        assert_eq!(la.resets_cooldown, lb.resets_cooldown);
+        match (la.remaining_secs(), lb.remaining_secs()) {
+            (Some(ra), Some(rb)) => assert!((ra - rb).abs() < 1e-4),
+            (ra, rb) => assert_eq!(ra, rb),
+        }
+    }
+}
+
+#[test]
+fn capture_and_apply_round_trip() {
+    let mut app = app();
+    let world = app.world_mut();
+    let source = spawn_damaged(world);
+    let target = world.spawn(PowerBundle::<Power>::new()).id();
+
+    let snapshot = PowerSnapshot::<Power>::capture(world, source).unwrap();
+    snapshot.apply(world, target).unwrap();
+
+    assert_same_state(world, source, target);
+    assert_eq!(
+        world.get::<PowerLimits>(target).unwrap().limits[0].remaining_secs(),
+        Some(3.5)
+    );
+}
+
+#[test]
+fn capture_requires_power_bar() {
+    let mut app = app();
+    let entity = app.world_mut().spawn_empty().id();
+    assert_eq!(
+        PowerSnapshot::<Power>::capture(app.world(), entity).unwrap_err(),
+        PowerError::MissingPowerBar(entity)
+    );
+}
+
+#[test]
+fn newer_snapshot_versions_are_rejected() {
+    let mut app = app();
+    let world = app.world_mut();
+    let source = spawn_damaged(world);
+
+    let mut snapshot = PowerSnapshot::<Power>::capture(world, source).unwrap();
+    snapshot.version += 1;
+    assert_eq!(
+        snapshot.apply(world, source).unwrap_err(),
+        PowerError::UnsupportedSnapshotVersion(snapshot.version)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serialized_round_trip() {
+    let mut app = app();
+    let world = app.world_mut();
+    let source = spawn_damaged(world);
+    let target = world.spawn(PowerBundle::<Power>::new()).id();
+
+    let snapshot = PowerSnapshot::<Power>::capture(world, source).unwrap();
+    let text = ron::to_string(&snapshot).unwrap();
+    let restored: PowerSnapshot = ron::from_str(&text).unwrap();
+    assert_eq!(restored.version, POWER_SNAPSHOT_VERSION);
+    restored.apply(world, target).unwrap();
+
+    assert_same_state(world, source, target);
+}
+
+#[test]
+fn knockout_progress_survives_a_round_trip() {
+    let mut app = app();
+    let policy = KnockoutPolicy::AutoRevive {
+        delay: 1.0,
+        power: 30.0,
+    };
+    let source = app
+        .world_mut()
+        .spawn((PowerBundle::<Power>::new(), policy))
+        .id();
+    app.update();
+    app.world_mut()
+        .write_message(PowerChangeEvent::<Power>::drain(source, 100.0));
+    app.update();
+    app.update();
+
+    let world = app.world_mut();
+    let mut modifiers = RegenModifiers::<Power>::default();
+    modifiers.add(RegenModifier::new(
+        1,
+        RegenModifierKind::Multiply(2.0),
+        Some(4.0),
+    ));
+    let mut drains = PowerDrains::<Power>::default();
+    drains.start(PowerDrain::new(1, 3.0));
+    world.entity_mut(source).insert((modifiers, drains));
+
+    let target = world.spawn((PowerBundle::<Power>::new(), policy)).id();
+    let snapshot = PowerSnapshot::<Power>::capture(world, source).unwrap();
+    snapshot.apply(world, target).unwrap();
+
+    let (state_a, state_b) = (
+        world.get::<KnockoutState>(source).unwrap(),
+        world.get::<KnockoutState>(target).unwrap(),
+    );
+    assert_eq!(state_a.remaining_secs(), state_b.remaining_secs());
+    assert!(state_b.remaining_secs().unwrap() < 1.0);
+    let modifiers = world.get::<RegenModifiers>(target).unwrap();
+    assert_eq!(modifiers.modifiers[0].remaining_secs(), Some(4.0));
+    assert!(world.get::<PowerDrains>(target).unwrap().is_active(1));
+
+    // The restored timer keeps running and revives the target
+    for _ in 0..4 {
+        app.update();
+    }
+    let power_bar = app.world().get::<PowerBar>(target).unwrap();
+    assert!(!power_bar.is_knocked_out);
+}
+
+#[test]
+fn knocked_out_bar_without_state_restarts_its_policy() {
+    let mut app = app();
+    let world = app.world_mut();
+    let source = world.spawn(PowerBundle::<Power>::new()).id();
+    let mut snapshot = PowerSnapshot::<Power>::capture(world, source).unwrap();
+    snapshot.power_bar.current = 0.0;
+    snapshot.power_bar.is_knocked_out = true;
+    snapshot.knockout_state = None;
+
+    let target = world
+        .spawn((
+            PowerBundle::<Power>::new(),
+            KnockoutPolicy::AutoRevive {
+                delay: 2.0,
+                power: 30.0,
+            },
+        ))
+        .id();
+    snapshot.apply(world, target).unwrap();
+    let state = world.get::<KnockoutState>(target).unwrap();
+    assert_eq!(state.remaining_secs(), Some(2.0));
+
+    // Restoring a bar that is up clears a stale knockout
+    let mut snapshot = PowerSnapshot::<Power>::capture(world, source).unwrap();
+    snapshot.knockout_state = None;
+    snapshot.apply(world, target).unwrap();
+    assert!(world.get::<KnockoutState>(target).is_none());
+}
+
+/// Entity in a grace window holding back a limit
+fn spawn_in_grace(app: &mut App) -> Entity {
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(GraceWindow::<Power>::new(&GracePolicy {
+            duration: 1.0,
+            ..Default::default()
+        }));
+    app.world_mut()
+        .write_message(ApplyLimitEvent::<Power>::points(
+            entity,
+            4,
+            30.0,
+            Color::WHITE,
+            None,
+            false,
+        ));
+    app.update();
+    entity
+}
+
+#[test]
+fn grace_window_survives_a_round_trip() {
+    let mut app = app();
+    let source = spawn_in_grace(&mut app);
+    let world = app.world_mut();
+    let target = world.spawn(PowerBundle::<Power>::new()).id();
+
+    let snapshot = PowerSnapshot::<Power>::capture(world, source).unwrap();
+    snapshot.apply(world, target).unwrap();
+    let grace = world.get::<GraceWindow>(target).unwrap();
+    assert_eq!(grace.remaining_secs(), 0.75);
+    assert_eq!(grace.deferred_limits.len(), 1);
+    assert_eq!(grace.deferred_limits[0].entity, target);
+
+    // The deferred limit lands on the target once its window ends
+    for _ in 0..4 {
+        app.update();
+    }
+    let world = app.world();
+    assert!(world.get::<GraceWindow>(target).is_none());
+    assert_eq!(world.get::<PowerBar>(target).unwrap().max, 70.0);
+}
+
+#[test]
+fn transient_state_missing_from_the_snapshot_is_removed() {
+    let mut app = app();
+    let target = spawn_in_grace(&mut app);
+    let world = app.world_mut();
+    let mut drains = PowerDrains::<Power>::default();
+    drains.start(PowerDrain::new(1, 3.0));
+    let mut modifiers = RegenModifiers::<Power>::default();
+    modifiers.add(RegenModifier::new(1, RegenModifierKind::Block, None));
+    world.entity_mut(target).insert((drains, modifiers));
+
+    let source = world.spawn(PowerBar::<Power>::new(100.0)).id();
+    let snapshot = PowerSnapshot::<Power>::capture(world, source).unwrap();
+    snapshot.apply(world, target).unwrap();
+    assert!(world.get::<PowerDrains>(target).is_none());
+    assert!(world.get::<RegenModifiers>(target).is_none());
+    assert!(world.get::<GraceWindow>(target).is_none());
+    // Persistent components are kept
+    assert!(world.get::<PowerLimits>(target).is_some());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn timers_serialize_as_seconds() {
+    let mut app = app();
+    let source = spawn_in_grace(&mut app);
+    let world = app.world_mut();
+    let mut modifiers = RegenModifiers::<Power>::default();
+    modifiers.add(RegenModifier::new(1, RegenModifierKind::Block, Some(4.0)));
+    let mut state = KnockoutState::<Power>::for_policy(KnockoutPolicy::Downed { bleed_out: 5.0 });
+    state.update(2.0);
+    world.entity_mut(source).insert((modifiers, state));
+
+    let snapshot = PowerSnapshot::<Power>::capture(world, source).unwrap();
+    let text = ron::to_string(&snapshot).unwrap();
+    assert!(!text.contains("stopwatch"));
+
+    let restored: PowerSnapshot = ron::from_str(&text).unwrap();
+    assert_eq!(restored.knockout_state.unwrap().remaining_secs(), Some(3.0));
+    assert_eq!(
+        restored.regen_modifiers.unwrap().modifiers[0].remaining_secs(),
+        Some(4.0)
+    );
+    let grace = restored.grace_window.unwrap();
+    assert_eq!(grace.remaining_secs(), 0.75);
+    assert_eq!(grace.deferred_limits[0].limit_type, LimitType::Points(30.0));
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "ui")]
+
+mod common;
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy_power::prelude::*;
+use common::app;
+
+/// Target and text of every bar widget, ordered from the top of the screen
+fn bars(app: &mut App) -> Vec<(Entity, String)> {
+    let mut bars = app
+        .world_mut()
+        .run_system_once(
+            |bars: Query<(Entity, &PowerBarUI, &Node)>,
+             children: Query<&Children>,
+             texts: Query<&Text>| {
+                bars.iter()
+                    .map(|(root, ui, node)| {
+                        let text = children
+                            .iter_descendants(root)
+                            .find_map(|child| texts.get(child).ok())
+                            .map(|text| text.0.clone())
+                            .unwrap_or_default();
+                        (node.top, ui.target, text)
+                    })
+                    .collect::<Vec<_>>()
+            },
+        )
+        .unwrap();
+    bars.sort_by(|a, b| match (a.0, b.0) {
+        (Val::Px(a), Val::Px(b)) => a.total_cmp(&b),
+        _ => std::cmp::Ordering::Equal,
+    });
+    bars.into_iter()
+        .map(|(_, target, text)| (target, text))
+        .collect()
+}
+
+#[test]
+fn every_bar_shows_its_own_entity() {
+    let mut app = app();
+    let player = app
+        .world_mut()
+        .spawn(PowerBundle::<Power>::with_max_power(100.0))
+        .id();
+    let companion = app
+        .world_mut()
+        .spawn(PowerBundle::<Power>::with_max_power(60.0))
+        .id();
+    app.update();
+
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Power>::new(companion, 20.0));
+    app.update();
+    assert_eq!(
+        bars(&mut app),
+        [
+            (player, "100 / 100".to_string()),
+            (companion, "40 / 60".to_string())
+        ]
+    );
+}
+
+#[test]
+fn bars_are_despawned_with_their_entity() {
+    let mut app = app();
+    let player = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    let companion = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+    app.update();
+    assert_eq!(bars(&mut app).len(), 2);
+
+    app.world_mut().despawn(player);
+    app.update();
+    assert_eq!(bars(&mut app), [(companion, "100 / 100".to_string())]);
+
+    // A new bar takes the freed slot at the top
+    let newcomer = app
+        .world_mut()
+        .spawn(PowerBundle::<Power>::with_max_power(50.0))
+        .id();
+    app.update();
+    app.update();
+    assert_eq!(
+        bars(&mut app),
+        [
+            (newcomer, "50 / 50".to_string()),
+            (companion, "100 / 100".to_string())
+        ]
+    );
+}
+
+#[test]
+fn bars_update_without_regeneration() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(PowerBundle::<Power>::new()).id();
+    app.update();
+    app.world_mut()
+        .entity_mut(entity)
+        .remove::<PowerRegeneration>();
+
+    app.world_mut()
+        .write_message(SpendPowerEvent::<Power>::new(entity, 30.0));
+    app.update();
+    assert_eq!(bars(&mut app), [(entity, "70 / 100".to_string())]);
+}